@@ -0,0 +1,5 @@
+pub mod command_line_options;
+pub mod render_command;
+pub mod get_command;
+pub mod set_command;
+pub mod export_nix_command;
\ No newline at end of file
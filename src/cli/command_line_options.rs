@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+use crate::cli::export_nix_command::ExportNixCommand;
+use crate::cli::get_command::GetCommand;
+use crate::cli::render_command::RenderCommand;
+use crate::cli::set_command::SetCommand;
+
+const CONFIG_DIRECTORY_OPTION: &str = "--config-dir";
+const RENDER_COMMAND: &str = "render";
+const GET_COMMAND: &str = "get";
+const SET_COMMAND: &str = "set";
+const EXPORT_NIX_COMMAND: &str = "export-nix";
+const COMMAND_NAMES: [&str; 4] = [RENDER_COMMAND, GET_COMMAND, SET_COMMAND, EXPORT_NIX_COMMAND];
+
+pub const USAGE_TEXT: &str = "Usage:
+  hyprsettings [--config-dir <directory>] [GTK options]
+  hyprsettings [--config-dir <directory>] render [--from <hyprsettings.yaml>] [--out <directory>]
+  hyprsettings [--config-dir <directory>] get <setting.path>
+  hyprsettings [--config-dir <directory>] set <setting.path> <value>
+  hyprsettings [--config-dir <directory>] export-nix [--from <hyprsettings.yaml>] [--out <file>]";
+
+#[derive(Debug, Clone)]
+pub enum CliCommand {
+    Render(RenderCommand),
+    Get(GetCommand),
+    Set(SetCommand),
+    ExportNix(ExportNixCommand),
+}
+
+impl CliCommand {
+    pub fn run(&self) -> i32 {
+        let command_result = match self {
+            CliCommand::Render(render_command) => render_command.run(),
+            CliCommand::Get(get_command) => get_command.run(),
+            CliCommand::Set(set_command) => set_command.run(),
+            CliCommand::ExportNix(export_nix_command) => export_nix_command.run(),
+        };
+
+        match command_result {
+            Ok(_) => 0,
+            Err(error) => {
+                eprintln!("{}", error);
+                1
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CommandLineOptions {
+    pub config_directory: Option<PathBuf>,
+    pub command: Option<CliCommand>,
+    pub remaining_arguments: Vec<String>,
+}
+
+impl CommandLineOptions {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, String> {
+        let mut command_line_options = CommandLineOptions::default();
+        let mut command_name = None;
+        let mut command_arguments = Vec::new();
+        let mut arguments = arguments.into_iter();
+
+        if let Some(program_name) = arguments.next() {
+            command_line_options.remaining_arguments.push(program_name);
+        }
+
+        while let Some(argument) = arguments.next() {
+            if let Some(config_directory) = Self::parse_option_value(CONFIG_DIRECTORY_OPTION, &argument, &mut arguments)? {
+                command_line_options.config_directory = Some(PathBuf::from(config_directory));
+                continue;
+            }
+
+            if command_name.is_some() {
+                command_arguments.push(argument);
+                continue;
+            }
+
+            if command_line_options.remaining_arguments.len() == 1 && COMMAND_NAMES.contains(&argument.as_str()) {
+                command_name = Some(argument);
+                continue;
+            }
+
+            command_line_options.remaining_arguments.push(argument);
+        }
+
+        command_line_options.command = match command_name.as_deref() {
+            Some(RENDER_COMMAND) => Some(CliCommand::Render(RenderCommand::parse(command_arguments)?)),
+            Some(GET_COMMAND) => Some(CliCommand::Get(GetCommand::parse(command_arguments)?)),
+            Some(SET_COMMAND) => Some(CliCommand::Set(SetCommand::parse(command_arguments)?)),
+            Some(EXPORT_NIX_COMMAND) => Some(CliCommand::ExportNix(ExportNixCommand::parse(command_arguments)?)),
+            _ => None,
+        };
+
+        Ok(command_line_options)
+    }
+
+    pub fn parse_option_value(
+        option: &str, argument: &str, arguments: &mut impl Iterator<Item = String>
+    ) -> Result<Option<String>, String> {
+        if argument == option {
+            return arguments.next()
+                .map(Some)
+                .ok_or(format!("Missing value after {}", option));
+        }
+
+        let option_value = argument.strip_prefix(option)
+            .and_then(|option_value| option_value.strip_prefix('='))
+            .map(|option_value| option_value.to_string());
+        Ok(option_value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+use crate::cli::command_line_options::CommandLineOptions;
+use crate::persistence::config_directory::ConfigDirectory;
+use crate::persistence::nix_settings_writer::NixSettingsWriter;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_reader::SettingsReader;
+use crate::persistence::settings_writer::SettingsWriter;
+use crate::persistence::yaml_settings_reader::YamlSettingsReader;
+
+const FROM_OPTION: &str = "--from";
+const OUT_OPTION: &str = "--out";
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportNixCommand {
+    pub from: Option<PathBuf>,
+    pub out: Option<PathBuf>,
+}
+
+impl ExportNixCommand {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, String> {
+        let mut export_nix_command = ExportNixCommand::default();
+        let mut arguments = arguments.into_iter();
+
+        while let Some(argument) = arguments.next() {
+            if let Some(from) = CommandLineOptions::parse_option_value(FROM_OPTION, &argument, &mut arguments)? {
+                export_nix_command.from = Some(PathBuf::from(from));
+                continue;
+            }
+
+            if let Some(out) = CommandLineOptions::parse_option_value(OUT_OPTION, &argument, &mut arguments)? {
+                export_nix_command.out = Some(PathBuf::from(out));
+                continue;
+            }
+
+            return Err(format!("Unknown export-nix option: {}", argument));
+        }
+
+        Ok(export_nix_command)
+    }
+
+    pub fn run(&self) -> Result<(), SettingsError> {
+        let settings_file_path = self.from.clone()
+            .unwrap_or(ConfigDirectory::get_path().join("hyprsettings.yaml"));
+
+        let mut reader = YamlSettingsReader::from_file(&settings_file_path.to_string_lossy());
+        reader.read_from_config()?;
+        let settings = reader.deserialize_settings();
+
+        let mut nix_settings_writer = NixSettingsWriter::new();
+        nix_settings_writer.serialize_settings(settings)?;
+
+        for mut rendered_config in nix_settings_writer.render_configs()? {
+            if let Some(out) = &self.out {
+                rendered_config.config_path = out.to_string_lossy().to_string();
+            }
+
+            rendered_config.write_to_file()?;
+            println!("{}", rendered_config.config_path);
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_path::SettingsPath;
+use crate::providers::application_provider::ApplicationProvider;
+
+#[derive(Debug, Clone)]
+pub struct GetCommand {
+    pub path: String,
+}
+
+impl GetCommand {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, String> {
+        match arguments.as_slice() {
+            [path] => Ok(GetCommand { path: path.clone() }),
+            _ => Err("The get command expects exactly one setting path".to_string()),
+        }
+    }
+
+    pub fn run(&self) -> Result<(), SettingsError> {
+        let settings = ApplicationProvider::read_config_settings()?;
+
+        let value = SettingsPath::get_value(&settings, &self.path)?;
+        println!("{}", SettingsPath::format_value(&value)?);
+        Ok(())
+    }
+}
\ No newline at end of file
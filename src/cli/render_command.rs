@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+use crate::cli::command_line_options::CommandLineOptions;
+use crate::persistence::config_directory::ConfigDirectory;
+use crate::persistence::config_renderer::ConfigRenderer;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_reader::SettingsReader;
+use crate::persistence::yaml_settings_reader::YamlSettingsReader;
+
+const FROM_OPTION: &str = "--from";
+const OUT_OPTION: &str = "--out";
+const CURRENT_DIRECTORY: &str = ".";
+
+#[derive(Debug, Clone, Default)]
+pub struct RenderCommand {
+    pub from: Option<PathBuf>,
+    pub out: Option<PathBuf>,
+}
+
+impl RenderCommand {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, String> {
+        let mut render_command = RenderCommand::default();
+        let mut arguments = arguments.into_iter();
+
+        while let Some(argument) = arguments.next() {
+            if let Some(from) = CommandLineOptions::parse_option_value(FROM_OPTION, &argument, &mut arguments)? {
+                render_command.from = Some(PathBuf::from(from));
+                continue;
+            }
+
+            if let Some(out) = CommandLineOptions::parse_option_value(OUT_OPTION, &argument, &mut arguments)? {
+                render_command.out = Some(PathBuf::from(out));
+                continue;
+            }
+
+            return Err(format!("Unknown render option: {}", argument));
+        }
+
+        Ok(render_command)
+    }
+
+    pub fn run(&self) -> Result<(), SettingsError> {
+        let settings_file_path = self.from.clone()
+            .unwrap_or(ConfigDirectory::get_path().join("hyprsettings.yaml"));
+
+        // Rendering a foreign settings file never writes into the live config directory unless asked to
+        let out = self.out.clone()
+            .or_else(|| self.from.as_ref().map(|_| PathBuf::from(CURRENT_DIRECTORY)));
+        if let Some(out) = out {
+            ConfigDirectory::set_override(out);
+        }
+
+        // The rendered settings file is only read, so older schema versions are migrated in memory only
+        let mut reader = YamlSettingsReader::from_file(&settings_file_path.to_string_lossy());
+        reader.disable_migration_backup();
+        reader.read_from_config()?;
+        let settings = reader.deserialize_settings();
+
+        for rendered_config in ConfigRenderer::render_hyprland_configs(&settings)? {
+            rendered_config.write_to_file()?;
+            println!("{}", rendered_config.config_path);
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
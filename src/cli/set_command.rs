@@ -0,0 +1,65 @@
+use crate::ipc::hyprland_ipc_client::HyprlandIpcClient;
+use crate::ipc::live_settings_applier::LiveSettingsApplier;
+use crate::persistence::config_backup::ConfigBackup;
+use crate::persistence::config_renderer::ConfigRenderer;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_path::SettingsPath;
+use crate::persistence::settings_profiles::SettingsProfiles;
+use crate::persistence::settings_validator::SettingsValidator;
+use crate::providers::application_provider::ApplicationProvider;
+
+#[derive(Debug, Clone)]
+pub struct SetCommand {
+    pub path: String,
+    pub value: String,
+}
+
+impl SetCommand {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, String> {
+        match arguments.as_slice() {
+            [path, value] => Ok(SetCommand { path: path.clone(), value: value.clone() }),
+            _ => Err("The set command expects a setting path and a value".to_string()),
+        }
+    }
+
+    pub fn run(&self) -> Result<(), SettingsError> {
+        let settings = ApplicationProvider::read_config_settings()?;
+
+        let settings = SettingsPath::set_value(&settings, &self.path, &self.value)?;
+        for diagnostic in SettingsValidator::validate(&settings) {
+            if diagnostic.is_error() {
+                return Err(SettingsError::validation(diagnostic));
+            }
+
+            eprintln!("{}", diagnostic);
+        }
+
+        let rendered_configs = ConfigRenderer::render_all_configs(&settings)?;
+
+        let retention_count = settings.backup_settings.retention_count;
+        if retention_count > 0 {
+            ConfigBackup::new().backup_config_files()?;
+            ConfigBackup::remove_old_backups(retention_count)?;
+        }
+
+        let live_settings_applier = LiveSettingsApplier::from_current_config();
+        for rendered_config in rendered_configs {
+            rendered_config.write_to_file()?;
+            println!("{}", rendered_config.config_path);
+        }
+
+        // The active profile follows the written settings, as it does when saving in the GUI
+        if let Some(active_profile) = SettingsProfiles::get_active_profile() {
+            SettingsProfiles::save_profile(&active_profile, &settings)?;
+        }
+
+        // The settings are already saved at this point, a running Hyprland that cannot be updated is only reported
+        if let Some(hyprland_ipc_client) = HyprlandIpcClient::from_environment()
+            && let Err(error) = live_settings_applier.apply(&hyprland_ipc_client, &settings)
+        {
+            eprintln!("The settings were saved, but could not be applied to Hyprland: {}", error);
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod hyprland_ipc_client;
+pub mod live_settings_applier;
\ No newline at end of file
@@ -0,0 +1,139 @@
+use std::io::{Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::persistence::settings_error::SettingsError;
+
+const SOCKET_FILE_NAME: &str = ".socket.sock";
+const SUCCESS_REPLY: &str = "ok";
+const REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub struct HyprlandIpcClient {
+    socket_path: PathBuf,
+}
+
+impl HyprlandIpcClient {
+    pub fn new(socket_path: PathBuf) -> Self {
+        Self {
+            socket_path
+        }
+    }
+
+    pub fn from_environment() -> Option<Self> {
+        let runtime_directory = std::env::var("XDG_RUNTIME_DIR").ok()?;
+        let instance_signature = std::env::var("HYPRLAND_INSTANCE_SIGNATURE").ok()?;
+        let socket_path = Path::new(&runtime_directory)
+            .join("hypr")
+            .join(instance_signature)
+            .join(SOCKET_FILE_NAME);
+
+        Some(Self::new(socket_path))
+    }
+
+    pub fn send_keyword(&self, key: &str, value: &str) -> Result<(), SettingsError> {
+        self.send_command(&format!("keyword {} {}", key, value))
+    }
+
+    pub fn send_reload(&self) -> Result<(), SettingsError> {
+        self.send_command("reload")
+    }
+
+    pub fn send_request(&self, request: &str) -> Result<String, SettingsError> {
+        let socket_path = self.socket_path.to_string_lossy().to_string();
+        let mut reply = String::new();
+
+        UnixStream::connect(&self.socket_path)
+            .and_then(|mut stream| {
+                stream.set_read_timeout(Some(REPLY_TIMEOUT))?;
+                stream.write_all(request.as_bytes())?;
+                stream.shutdown(Shutdown::Write)?;
+                stream.read_to_string(&mut reply)
+            })
+            .map_err(|error| SettingsError::io(&socket_path, error))?;
+
+        Ok(reply)
+    }
+
+    fn send_command(&self, request: &str) -> Result<(), SettingsError> {
+        let reply = self.send_request(request)?;
+        if reply.trim() != SUCCESS_REPLY {
+            return Err(SettingsError::ipc(request, reply.trim()));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::os::unix::net::UnixListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread::JoinHandle;
+    use super::*;
+
+    static SOCKET_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    // Answers one connection per reply and returns the received requests once all replies are sent
+    pub(crate) fn spawn_mock_socket(replies: Vec<&'static str>) -> (HyprlandIpcClient, JoinHandle<Vec<String>>) {
+        let socket_path = std::env::temp_dir().join(format!(
+            "hyprsettings-ipc-{}-{}.sock", std::process::id(), SOCKET_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path).expect("mock socket can be bound");
+
+        let mock_socket = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for reply in replies {
+                let (mut stream, _) = listener.accept().expect("client connects");
+                let mut request = String::new();
+                stream.read_to_string(&mut request).expect("request can be read");
+                stream.write_all(reply.as_bytes()).expect("reply can be written");
+                requests.push(request);
+            }
+
+            requests
+        });
+
+        (HyprlandIpcClient::new(socket_path), mock_socket)
+    }
+
+    #[test]
+    fn send_keyword_sends_keyword_request() {
+        let (client, mock_socket) = spawn_mock_socket(vec!["ok"]);
+
+        assert!(client.send_keyword("general:gaps_in", "5").is_ok());
+        assert_eq!(mock_socket.join().unwrap(), vec!["keyword general:gaps_in 5"]);
+    }
+
+    #[test]
+    fn send_reload_sends_reload_request() {
+        let (client, mock_socket) = spawn_mock_socket(vec!["ok\n"]);
+
+        assert!(client.send_reload().is_ok());
+        assert_eq!(mock_socket.join().unwrap(), vec!["reload"]);
+    }
+
+    #[test]
+    fn rejected_reply_returns_ipc_error() {
+        let (client, mock_socket) = spawn_mock_socket(vec!["invalid field gaps"]);
+
+        let result = client.send_keyword("general:gaps", "5");
+        mock_socket.join().unwrap();
+
+        match result {
+            Err(SettingsError::Ipc { request, message }) => {
+                assert_eq!(request, "keyword general:gaps 5");
+                assert_eq!(message, "invalid field gaps");
+            },
+            other => panic!("expected an ipc error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn missing_socket_returns_io_error() {
+        let client = HyprlandIpcClient::new(std::env::temp_dir().join("hyprsettings-missing.sock"));
+
+        assert!(matches!(client.send_reload(), Err(SettingsError::Io { .. })));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,188 @@
+// Changed values are found by comparing the currently written hyprland config with the monitor, appearance and
+// input sections rendered from the new settings, so the sent keywords always match what ends up in the config files.
+// The current config has to be captured before the new config files are written.
+
+use std::collections::BTreeMap;
+use crate::ipc::hyprland_ipc_client::HyprlandIpcClient;
+use crate::models::settings::hyprland_settings::HyprlandSettings;
+use crate::models::settings::monitor_settings::MonitorSettings;
+use crate::persistence::config_directory::ConfigDirectory;
+use crate::persistence::hyprland_config_parser::HyprlandConfigParser;
+use crate::persistence::hyprland_settings_writer::HyprlandSettingsWriter;
+use crate::persistence::settings_error::SettingsError;
+
+const MONITOR_KEYWORD: &str = "monitor";
+const ENVIRONMENT_KEYWORD: &str = "env";
+
+pub struct LiveSettingsApplier {
+    current_config: HyprlandConfigParser,
+}
+
+impl LiveSettingsApplier {
+    pub fn from_current_config() -> Self {
+        let hyprland_config_path = ConfigDirectory::get_path().join("hyprland.conf");
+        let mut current_config = HyprlandConfigParser::new();
+
+        // Without a readable config every value counts as changed
+        let _ = current_config.parse_file(&hyprland_config_path.to_string_lossy());
+        Self {
+            current_config
+        }
+    }
+
+    pub fn apply(&self, client: &HyprlandIpcClient, settings: &HyprlandSettings) -> Result<(), SettingsError> {
+        let keyword_result = self.create_keywords(settings)
+            .iter()
+            .try_for_each(|(key, value)| client.send_keyword(key, value));
+
+        // A rejected keyword falls back to reloading the written config files as a whole
+        keyword_result.or_else(|keyword_error| client.send_reload().map_err(|_| keyword_error))
+    }
+
+    pub fn apply_monitor_settings(client: &HyprlandIpcClient, monitor_settings: &MonitorSettings) -> Result<(), SettingsError> {
+        monitor_settings.monitor_configurations
+            .iter()
+            .try_for_each(|(monitor_port, monitor_configuration)| {
+                let monitor_value = if monitor_configuration.enabled {
+                    HyprlandSettingsWriter::create_monitor_value(monitor_port, monitor_configuration)
+                } else {
+                    HyprlandSettingsWriter::create_disabled_monitor_value(monitor_port)
+                };
+
+                client.send_keyword(MONITOR_KEYWORD, &monitor_value)
+            })
+    }
+
+    pub fn create_keywords(&self, settings: &HyprlandSettings) -> Vec<(String, String)> {
+        let mut live_config = HyprlandConfigParser::new();
+        live_config.parse_content(&HyprlandSettingsWriter::render_live_config(settings), "");
+
+        // Environment variables only reach newly started programs, so they are not sent
+        let mut keywords: Vec<(String, String)> = live_config.get_entries()
+            .iter()
+            .filter(|entry| entry.key != MONITOR_KEYWORD && entry.key != ENVIRONMENT_KEYWORD)
+            .filter(|entry| self.current_config.get_value(&entry.key).as_ref() != Some(&entry.value))
+            .map(|entry| (entry.key.clone(), entry.value.clone()))
+            .collect();
+
+        let current_monitors = Self::index_monitor_values(self.current_config.get_values(MONITOR_KEYWORD));
+        let live_monitors = Self::index_monitor_values(live_config.get_values(MONITOR_KEYWORD));
+        for (monitor_port, monitor_value) in &live_monitors {
+            if current_monitors.get(monitor_port) != Some(monitor_value) {
+                keywords.push((MONITOR_KEYWORD.to_string(), monitor_value.clone()));
+            }
+        }
+
+        keywords
+    }
+
+    fn index_monitor_values(monitor_values: Vec<String>) -> BTreeMap<String, String> {
+        monitor_values.into_iter()
+            .map(|monitor_value| {
+                let monitor_port = monitor_value.split(',').next().unwrap_or_default().trim().to_string();
+                (monitor_port, monitor_value)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ipc::hyprland_ipc_client::tests::spawn_mock_socket;
+    use crate::models::monitor::monitor_configuration::MonitorConfiguration;
+    use crate::models::monitor::video_mode::VideoMode;
+    use super::*;
+
+    fn create_settings() -> HyprlandSettings {
+        let mut settings = HyprlandSettings::default();
+        settings.appearance_settings.inner_gap = 5.0;
+        settings.appearance_settings.cursor_size = 24;
+
+        let mut monitor_configuration = MonitorConfiguration {
+            enabled: true,
+            resolution_scale: 1.0,
+            ..Default::default()
+        };
+        monitor_configuration.video_mode = VideoMode {
+            width_resolution: 1920,
+            height_resolution: 1080,
+            refresh_rate: 60.0,
+        };
+        settings.monitor_settings.monitor_configurations.insert("DP-1".to_string(), monitor_configuration);
+        settings
+    }
+
+    fn create_applier(current_settings: &HyprlandSettings) -> LiveSettingsApplier {
+        let mut current_config = HyprlandConfigParser::new();
+        current_config.parse_content(&HyprlandSettingsWriter::render_live_config(current_settings), "");
+        LiveSettingsApplier {
+            current_config
+        }
+    }
+
+    #[test]
+    fn unchanged_settings_create_no_keywords() {
+        let settings = create_settings();
+
+        assert!(create_applier(&settings).create_keywords(&settings).is_empty());
+    }
+
+    #[test]
+    fn changed_value_creates_only_its_keyword() {
+        let current_settings = create_settings();
+        let mut settings = current_settings.clone();
+        settings.appearance_settings.inner_gap = 10.0;
+
+        let keywords = create_applier(&current_settings).create_keywords(&settings);
+        assert_eq!(keywords, vec![("general:gaps_in".to_string(), "10".to_string())]);
+    }
+
+    #[test]
+    fn changed_environment_variable_creates_no_keyword() {
+        let current_settings = create_settings();
+        let mut settings = current_settings.clone();
+        settings.appearance_settings.cursor_size = 32;
+
+        assert!(create_applier(&current_settings).create_keywords(&settings).is_empty());
+    }
+
+    #[test]
+    fn changed_monitor_creates_monitor_keyword() {
+        let current_settings = create_settings();
+        let mut settings = current_settings.clone();
+        if let Some(monitor_configuration) = settings.monitor_settings.monitor_configurations.get_mut("DP-1") {
+            monitor_configuration.resolution_scale = 1.5;
+        }
+
+        let keywords = create_applier(&current_settings).create_keywords(&settings);
+        assert_eq!(keywords.len(), 1);
+        assert_eq!(keywords[0].0, MONITOR_KEYWORD);
+        assert!(keywords[0].1.starts_with("DP-1, 1920x1080@60, 0x0, 1.5"));
+    }
+
+    #[test]
+    fn disabled_monitor_creates_disable_keyword_once() {
+        let current_settings = create_settings();
+        let mut settings = current_settings.clone();
+        if let Some(monitor_configuration) = settings.monitor_settings.monitor_configurations.get_mut("DP-1") {
+            monitor_configuration.enabled = false;
+        }
+
+        let keywords = create_applier(&current_settings).create_keywords(&settings);
+        assert_eq!(keywords, vec![(MONITOR_KEYWORD.to_string(), "DP-1, disable".to_string())]);
+    }
+
+    #[test]
+    fn rejected_keyword_falls_back_to_reload() {
+        let current_settings = create_settings();
+        let mut settings = current_settings.clone();
+        settings.appearance_settings.inner_gap = 10.0;
+        settings.appearance_settings.outer_gap = 20.0;
+
+        let (client, mock_socket) = spawn_mock_socket(vec!["invalid value", "ok"]);
+        let result = create_applier(&current_settings).apply(&client, &settings);
+
+        assert!(result.is_ok());
+        assert_eq!(mock_socket.join().unwrap(), vec!["keyword general:gaps_in 10", "reload"]);
+    }
+}
\ No newline at end of file
@@ -3,11 +3,15 @@ pub mod providers;
 pub mod models;
 pub mod utils;
 pub mod persistence;
+pub mod cli;
+pub mod ipc;
 mod types;
 mod math;
 
 use gtk::prelude::*;
 use gtk::{Application, ApplicationWindow};
+use crate::cli::command_line_options::{CommandLineOptions, USAGE_TEXT};
+use crate::persistence::config_directory::ConfigDirectory;
 use crate::ui::controls::Control;
 use crate::ui::css_styler::CSSStyler;
 use crate::ui::managed_control::ManagedControl;
@@ -16,12 +20,28 @@ use crate::ui::pages::app::App;
 use crate::utils::new_rc_mut;
 
 fn main() {
+    let command_line_options = match CommandLineOptions::parse(std::env::args().collect()) {
+        Ok(command_line_options) => command_line_options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE_TEXT);
+            std::process::exit(2);
+        }
+    };
+
+    if let Some(config_directory) = command_line_options.config_directory {
+        ConfigDirectory::set_override(config_directory);
+    }
+
+    if let Some(command) = command_line_options.command {
+        std::process::exit(command.run());
+    }
+
     let application = Application::builder()
         .application_id("jinoworks.hyprsettings")
         .build();
     
     application.connect_activate(application_activation_setup);
-    application.run();
+    application.run_with_args(&command_line_options.remaining_arguments);
 }
 
 fn application_activation_setup(application: &Application) {
@@ -40,4 +60,5 @@ fn application_activation_setup(application: &Application) {
     let app = App::new();
     window.set_child(Some(app.get_widget()));
     window.present();
+    app.present_settings_errors();
 }
\ No newline at end of file
@@ -1,17 +1,30 @@
 use serde::{Deserialize, Serialize};
 use crate::models::keybinds::key_bind_configuration::KeyBindConfiguration;
 
+pub const DEFAULT_BIND_TYPE: &str = "bind";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomKeybind {
     pub command: String,
-    pub keybind: KeyBindConfiguration
+    pub keybind: KeyBindConfiguration,
+    #[serde(default = "CustomKeybind::get_default_bind_type")]
+    pub bind_type: String,
 }
 
 impl CustomKeybind {
     pub fn new(command: String, keybind: KeyBindConfiguration) -> Self {
         Self {
             command,
-            keybind
+            keybind,
+            bind_type: Self::get_default_bind_type(),
         }
     }
+
+    pub fn set_bind_type(&mut self, bind_type: String) {
+        self.bind_type = bind_type;
+    }
+
+    fn get_default_bind_type() -> String {
+        DEFAULT_BIND_TYPE.to_string()
+    }
 }
\ No newline at end of file
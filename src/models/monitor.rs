@@ -1,4 +1,5 @@
 pub mod monitor_information;
 pub mod monitor_configuration;
 pub mod video_mode;
-pub mod monitor_info_parser;
\ No newline at end of file
+pub mod monitor_info_parser;
+pub mod monitor_layout_profile;
\ No newline at end of file
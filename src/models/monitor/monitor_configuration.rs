@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
 use crate::models::monitor::monitor_information::MonitorInformation;
-use crate::models::monitor::video_mode::VideoMode;
+use crate::models::monitor::video_mode::{VideoMode, VideoModePreset};
 use crate::math::vector::Vector;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -98,6 +98,19 @@ impl MonitorOrientation {
             MonitorOrientation::FlippedRotation270 => 7,
         }
     }
+
+    pub fn from_hyprland_orientation_code(code: u8) -> Self {
+        match code {
+            1 => MonitorOrientation::Rotation90,
+            2 => MonitorOrientation::Rotation180,
+            3 => MonitorOrientation::Rotation270,
+            4 => MonitorOrientation::Flipped,
+            5 => MonitorOrientation::FlippedRotation90,
+            6 => MonitorOrientation::FlippedRotation180,
+            7 => MonitorOrientation::FlippedRotation270,
+            _ => MonitorOrientation::None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -105,7 +118,87 @@ pub struct MonitorConfiguration {
     pub enabled: bool,
     pub information: MonitorInformation,
     pub video_mode: VideoMode,
+    #[serde(default)]
+    pub video_mode_preset: VideoModePreset,
     pub offset: Vector,
     pub resolution_scale: f32,
     pub orientation: MonitorOrientation,
+}
+
+impl MonitorConfiguration {
+    pub fn get_video_mode_option_names(&self) -> Vec<String> {
+        let mut option_names = VideoModePreset::get_preset_option_names();
+        option_names.extend(self.information.video_modes.iter().map(|video_mode| video_mode.to_string()));
+
+        // Modes read from a config file might not be advertised by the monitor, they stay selectable until changed
+        let has_unlisted_video_mode = self.video_mode.get_pixel_count() > 0
+            && !self.information.has_video_mode(&self.video_mode);
+        if has_unlisted_video_mode {
+            option_names.push(self.video_mode.to_string());
+        }
+
+        option_names
+    }
+
+    pub fn get_selected_video_mode_option(&self) -> String {
+        match self.video_mode_preset {
+            VideoModePreset::Custom => self.information.find_video_mode(&self.video_mode)
+                .unwrap_or(&self.video_mode)
+                .to_string(),
+            video_mode_preset => video_mode_preset.to_string(),
+        }
+    }
+
+    pub fn select_video_mode_option(&mut self, option_name: &str) {
+        let selected_video_mode = self.information.video_modes.iter()
+            .find(|video_mode| video_mode.to_string() == option_name)
+            .cloned();
+
+        match selected_video_mode {
+            Some(video_mode) => {
+                self.video_mode_preset = VideoModePreset::Custom;
+                self.video_mode = video_mode;
+            }
+            None => self.set_video_mode_preset(VideoModePreset::from(option_name.to_string())),
+        }
+    }
+
+    pub fn set_video_mode_preset(&mut self, video_mode_preset: VideoModePreset) {
+        self.video_mode_preset = video_mode_preset;
+
+        // The resolved mode is only used for the layout preview and validation, Hyprland resolves the preset itself
+        if let Some(video_mode) = self.information.get_video_mode_by_preset(video_mode_preset) {
+            self.video_mode = video_mode;
+        }
+    }
+
+    pub fn get_logical_size(&self) -> Vector {
+        let resolution_scale = if self.resolution_scale > 0.0 { self.resolution_scale as f64 } else { 1.0 };
+
+        let size = Vector::new(self.video_mode.width_resolution as f64, self.video_mode.height_resolution as f64)
+            .mul_by(1.0 / resolution_scale);
+        self.orientation.get_size_by_orientation(size)
+    }
+
+    pub fn overlaps(&self, other_configuration: &MonitorConfiguration) -> bool {
+        let monitor_start = &self.offset;
+        let monitor_end = monitor_start.add(&self.get_logical_size());
+        let other_monitor_start = &other_configuration.offset;
+        let other_monitor_end = other_monitor_start.add(&other_configuration.get_logical_size());
+
+        monitor_start.get_x() < other_monitor_end.get_x() && other_monitor_start.get_x() < monitor_end.get_x()
+            && monitor_start.get_y() < other_monitor_end.get_y() && other_monitor_start.get_y() < monitor_end.get_y()
+    }
+
+    pub fn get_hyprland_video_mode(&self) -> String {
+        match self.video_mode_preset.get_hyprland_mode_name() {
+            Some(mode_name) => mode_name.to_string(),
+            None => format!(
+                "{}x{}@{}",
+                self.video_mode.width_resolution,
+                self.video_mode.height_resolution,
+                self.video_mode.get_refresh_rate_text()
+            ),
+        }
+    }
 }
\ No newline at end of file
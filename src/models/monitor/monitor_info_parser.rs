@@ -1,179 +1,177 @@
-// This parser parses the output of the stdout output from the wlr-randr cli tool.
-// Based on the output, the monitor information will be estimated, since no core packages exist to easily
-// retrieve monitor information in wayland as in of July 2025.
+// This parser parses the stdout output of the wlr-randr cli tool, which is the fallback monitor backend when hyprctl
+// is not available. Every monitor starts with an unindented line holding its port name and description, followed by
+// indented "Field: value" lines and the video modes listed below the "Modes:" field. Lines that cannot be parsed are
+// skipped, so format changes of wlr-randr lose single values instead of aborting the detection.
 
+use crate::math::vector::Vector;
+use crate::models::monitor::monitor_configuration::{MonitorConfiguration, MonitorOrientation};
 use crate::models::monitor::monitor_information::MonitorInformation;
-use crate::models::monitor::video_mode::VideoMode;
+use crate::models::monitor::video_mode::{VideoMode, VideoModePreset};
+
+const MAKE_FIELD: &str = "Make";
+const MODEL_FIELD: &str = "Model";
+const SERIAL_FIELD: &str = "Serial";
+const ENABLED_FIELD: &str = "Enabled";
+const POSITION_FIELD: &str = "Position";
+const TRANSFORM_FIELD: &str = "Transform";
+const SCALE_FIELD: &str = "Scale";
+const MODES_FIELD: &str = "Modes";
+const CURRENT_MODE_MARKER: &str = "current";
+const TRANSFORM_NAMES: [&str; 8] = [
+    "normal", "90", "180", "270", "flipped", "flipped-90", "flipped-180", "flipped-270"
+];
 
 #[derive(Debug)]
 pub struct MonitorInfoParser {
-    monitor_infos: Vec<MonitorInformation>,
+    monitor_configurations: Vec<MonitorConfiguration>,
 }
 
 impl MonitorInfoParser {
     pub fn new() -> Self {
         MonitorInfoParser {
-            monitor_infos: vec!()
+            monitor_configurations: vec!()
         }
     }
 
-    pub fn parse_output(&mut self, wlrrandr_output: &String) {
-        if self.monitor_infos.len() == 0 {
-            self.monitor_infos = vec!();
-        }
-
-        let mut multi_video_mode = false;
-        let monitor_segments = self.get_monitor_segments(wlrrandr_output);
-        for monitor_segment in monitor_segments {
-            let mut port_name = String::new();
-            let mut brand_name = String::new();
-            let mut model_name = String::new();
-            let mut serial_number = String::new();
-            let mut monitor_video_modes: Vec<VideoMode> = vec!();
-
-            let mut line_index = 0;
-            for line in &monitor_segment {
-                if line_index == 0 {
-                    let split_monitor_name = line
-                        .split(" ")
-                        .map(|x| x.to_string())
-                        .collect::<Vec<String>>();
-
-                    port_name = split_monitor_name[0].clone();
-
-                    line_index += 1;
-                    continue;
-                }
-
-                if line.contains("Make:") {
-                    brand_name = self.parse_field(String::from("Make: "), &line);
-                }
-
-                if line.contains("Model:") {
-                    model_name = self.parse_field(String::from("Model: "), &line);
-                }
+    pub fn parse_output(&mut self, wlrrandr_output: &str) {
+        let mut monitor_configuration: Option<MonitorConfiguration> = None;
+        let mut monitor_video_modes: Vec<VideoMode> = Vec::new();
+        let mut current_video_mode: Option<VideoMode> = None;
+        let mut is_mode_list = false;
 
-                if line.contains("Serial:") {
-                    serial_number = self.parse_field(String::from("Serial: "), &line);
-                }
-
-                if line.contains("Modes:") {
-                    multi_video_mode = true;
-                    continue;
-                }
-
-                if multi_video_mode {
-                    if !line.starts_with("    ") {
-                        multi_video_mode = false;
-                        continue;
-                    }
-
-                    let video_mode = self.parse_video_mode(&line);
-                    monitor_video_modes.push(video_mode);
-                }
+        for line in wlrrandr_output.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
 
-                line_index += 1
+            if !line.starts_with(char::is_whitespace) {
+                self.add_monitor_configuration(
+                    monitor_configuration.take(), std::mem::take(&mut monitor_video_modes), current_video_mode.take()
+                );
+                monitor_configuration = Self::parse_monitor_header(line);
+                is_mode_list = false;
+                continue;
             }
 
-            monitor_video_modes.sort_by(|first_mode, second_mode| {
-                first_mode.height_resolution.cmp(&second_mode.height_resolution)
-                    .then(first_mode.width_resolution.cmp(&second_mode.width_resolution))
-                    .then(first_mode.refresh_rate.cmp(&second_mode.refresh_rate))
-            });
+            let Some(configuration) = monitor_configuration.as_mut() else {
+                continue;
+            };
 
-            let mut min_video_mode = monitor_video_modes[0].clone();
-            let max_video_mode = monitor_video_modes[monitor_video_modes.len() -1].clone();
+            let line = line.trim();
+            if is_mode_list && let Some(video_mode) = Self::parse_video_mode(line) {
+                if line.contains(CURRENT_MODE_MARKER) {
+                    current_video_mode = Some(video_mode.clone());
+                }
 
-            if min_video_mode.refresh_rate > max_video_mode.refresh_rate {
-                min_video_mode.refresh_rate = max_video_mode.refresh_rate;
+                monitor_video_modes.push(video_mode);
+                continue;
             }
 
-            let monitor_information = MonitorInformation {
-                port_name,
-                brand_name,
-                model_name,
-                serial_number,
-                min_video_mode,
-                max_video_mode,
+            let Some((field_name, field_value)) = line.split_once(':') else {
+                continue;
             };
 
-            self.monitor_infos.push(monitor_information);
+            let field_value = field_value.trim().to_string();
+            is_mode_list = field_name == MODES_FIELD;
+            match field_name {
+                MAKE_FIELD => configuration.information.brand_name = field_value,
+                MODEL_FIELD => configuration.information.model_name = field_value,
+                SERIAL_FIELD => configuration.information.serial_number = field_value,
+                ENABLED_FIELD => configuration.enabled = field_value == "yes",
+                POSITION_FIELD => {
+                    if let Some(offset) = Self::parse_position(&field_value) {
+                        configuration.offset = offset;
+                    }
+                },
+                TRANSFORM_FIELD => {
+                    if let Some(orientation) = Self::parse_transform(&field_value) {
+                        configuration.orientation = orientation;
+                    }
+                },
+                SCALE_FIELD => {
+                    if let Some(scale) = field_value.parse::<f32>().ok().filter(|scale| *scale > 0.0) {
+                        configuration.resolution_scale = scale;
+                    }
+                },
+                _ => {}
+            }
         }
-    }
 
-    pub fn get_result(&self) -> &Vec<MonitorInformation> {
-        &self.monitor_infos
+        self.add_monitor_configuration(monitor_configuration, monitor_video_modes, current_video_mode);
     }
 
-    fn get_monitor_segments(&self, wlrrandr_output: &String) -> Vec<Vec<String>> {
-        let mut last_line_index: u32 = 0;
-        let mut line_index: u32 = 0;
-        let mut monitor_segments_output: Vec<Vec<String>> = vec!();
+    pub fn get_result(&self) -> &Vec<MonitorConfiguration> {
+        &self.monitor_configurations
+    }
 
-        for line in wlrrandr_output.lines() {
-            let leading_line_character = line.chars().nth(0).unwrap();
-            if leading_line_character != ' ' && line_index > 0 || line_index == (wlrrandr_output.lines().count() - 1) as u32 {
-                let monitor_segment_lines: Vec<String> = wlrrandr_output
-                    .lines()
-                    .skip(last_line_index as usize)
-                    .take((line_index - last_line_index) as usize)
-                    .map(|line | line.to_string())
-                    .collect();
-
-                monitor_segments_output.push(monitor_segment_lines);
-                last_line_index = line_index;
-            }
+    fn add_monitor_configuration(
+        &mut self, monitor_configuration: Option<MonitorConfiguration>, video_modes: Vec<VideoMode>,
+        current_video_mode: Option<VideoMode>
+    ) {
+        if let Some(mut monitor_configuration) = monitor_configuration {
+            monitor_configuration.information.set_video_modes(video_modes);
+
+            // Disabled monitors have no current video mode
+            let information = &monitor_configuration.information;
+            monitor_configuration.video_mode = current_video_mode
+                .and_then(|current_video_mode| information.find_video_mode(&current_video_mode).cloned())
+                .unwrap_or(information.max_video_mode.clone());
+            self.monitor_configurations.push(monitor_configuration);
+        }
+    }
 
-            line_index += 1;
+    fn parse_monitor_header(line: &str) -> Option<MonitorConfiguration> {
+        let (port_name, description) = line.split_once(' ').unwrap_or((line, ""));
+        if port_name.is_empty() {
+            return None;
         }
 
+        // The description repeats the port name in parentheses, e.g. "Dell Inc. DELL U2719D 8H2QX23 (DP-1)"
+        let port_suffix = format!("({})", port_name);
+        let description = description.trim().trim_matches('"');
+        let description = description.strip_suffix(port_suffix.as_str()).unwrap_or(description);
+
+        let information = MonitorInformation {
+            port_name: port_name.to_string(),
+            description: description.trim().to_string(),
+            ..Default::default()
+        };
+
+        // Older wlr-randr versions omit some fields, which then keep these values
+        Some(MonitorConfiguration {
+            enabled: true,
+            information,
+            video_mode: VideoMode::default(),
+            video_mode_preset: VideoModePreset::Custom,
+            offset: Vector::new(0.0, 0.0),
+            resolution_scale: 1.0,
+            orientation: MonitorOrientation::None,
+        })
+    }
 
-        monitor_segments_output
+    fn parse_video_mode(line: &str) -> Option<VideoMode> {
+        // e.g. "2560x1440 px, 59.951000 Hz (preferred, current)"
+        let (resolution_part, refresh_rate_part) = line.split_once(',')?;
+        let resolution = resolution_part.split_whitespace().next()?;
+        let (width_resolution, height_resolution) = resolution.split_once('x')?;
+        let refresh_rate = refresh_rate_part.split_whitespace().next()?;
+
+        Some(VideoMode {
+            width_resolution: width_resolution.parse::<u32>().ok()?,
+            height_resolution: height_resolution.parse::<u32>().ok()?,
+            refresh_rate: refresh_rate.parse::<f32>().ok()?,
+        })
     }
 
-    fn parse_field(&self, field_name: String, line_content: &String) -> String {
-        const VALUE_START_OFFSET: usize = 2;
-        
-        let field_name_start_index = line_content.find(line_content.as_str())
-            .expect("Cannot find field name start index");
-        let field_name_end_index = field_name_start_index + field_name.len();
-        let value_start_index = field_name_end_index + VALUE_START_OFFSET;
-        line_content[value_start_index..line_content.len()].to_string()
+    fn parse_position(field_value: &str) -> Option<Vector> {
+        // e.g. "2560,0"
+        let (x_position, y_position) = field_value.split_once(',')?;
+        Some(Vector::new(x_position.trim().parse::<f64>().ok()?, y_position.trim().parse::<f64>().ok()?))
     }
 
-    fn parse_video_mode(&self, line_content: &String) -> VideoMode {
-        const TWO_TAB_SPACES: usize = 4;
-        const RESOLUTION_SPLIT_INDEX: usize = 0;
-        const REFRESH_RATE_SPLIT_INDEX: usize = 2;
-        const WIDTH_RESOLUTION_SPLIT_INDEX: usize = 0;
-        const HEIGHT_RESOLUTION_SPLIT_INDEX: usize = 1;
-
-        let split_mode_entry = line_content
-            .split(" ")
-            .map(|x| x.to_string())
-            .skip(TWO_TAB_SPACES)
-            .collect::<Vec<String>>();
-
-        let frequency_part = &split_mode_entry[REFRESH_RATE_SPLIT_INDEX];
-        let resolution_part = &split_mode_entry[RESOLUTION_SPLIT_INDEX];
-        let split_resolution_part = resolution_part
-            .split("x")
-            .map(|x| x.to_string())
-            .collect::<Vec<String>>();
-
-        let max_width_resolution = &split_resolution_part[WIDTH_RESOLUTION_SPLIT_INDEX];
-        let max_height_resolution = &split_resolution_part[HEIGHT_RESOLUTION_SPLIT_INDEX];
-        let max_refresh_rate = frequency_part;
-
-        let parsed_max_width_resolution = max_width_resolution.parse::<u32>().unwrap_or_else(|_| 0);
-        let parsed_max_height_resolution = max_height_resolution.parse::<u32>().unwrap_or_else(|_| 0);
-        let parsed_max_refresh_rate_fixed = max_refresh_rate.parse::<f32>().unwrap_or_else(|_| 0.0);
-        let fixed_max_refresh_rate = parsed_max_refresh_rate_fixed.round() as u32;
-
-        VideoMode {
-            width_resolution: parsed_max_width_resolution, 
-            height_resolution: parsed_max_height_resolution, 
-            refresh_rate: fixed_max_refresh_rate,
-        }
+    fn parse_transform(field_value: &str) -> Option<MonitorOrientation> {
+        TRANSFORM_NAMES.iter()
+            .position(|transform_name| *transform_name == field_value)
+            .map(|orientation_code| MonitorOrientation::from_hyprland_orientation_code(orientation_code as u8))
     }
 }
\ No newline at end of file
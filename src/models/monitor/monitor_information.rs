@@ -1,11 +1,89 @@
 use serde::{Deserialize, Serialize};
-use crate::models::monitor::video_mode::VideoMode;
+use crate::models::monitor::video_mode::{VideoMode, VideoModePreset};
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MonitorInformation {
     pub port_name: String,
     pub brand_name: String,
     pub model_name: String,
     pub serial_number: String,
+    #[serde(default)]
+    pub description: String,
     pub min_video_mode: VideoMode,
     pub max_video_mode: VideoMode,
+    #[serde(default)]
+    pub video_modes: Vec<VideoMode>,
+}
+
+impl MonitorInformation {
+    pub fn set_video_modes(&mut self, mut video_modes: Vec<VideoMode>) {
+        video_modes.sort_by(|first_mode, second_mode| {
+            first_mode.height_resolution.cmp(&second_mode.height_resolution)
+                .then(first_mode.width_resolution.cmp(&second_mode.width_resolution))
+                .then(first_mode.refresh_rate.total_cmp(&second_mode.refresh_rate))
+        });
+        video_modes.dedup_by(|video_mode, other_video_mode| video_mode.is_same_mode(other_video_mode));
+
+        let (Some(min_video_mode), Some(max_video_mode)) = (video_modes.first(), video_modes.last()) else {
+            return;
+        };
+
+        let mut min_video_mode = min_video_mode.clone();
+        if min_video_mode.refresh_rate > max_video_mode.refresh_rate {
+            min_video_mode.refresh_rate = max_video_mode.refresh_rate;
+        }
+
+        self.min_video_mode = min_video_mode;
+        self.max_video_mode = max_video_mode.clone();
+        self.video_modes = video_modes;
+    }
+
+    // Identifies the physical monitor independent of its port, wlr-randr reports a missing serial number as "(null)"
+    pub fn get_identity(&self) -> String {
+        let identity = [&self.brand_name, &self.model_name, &self.serial_number].iter()
+            .map(|identity_part| identity_part.trim())
+            .filter(|identity_part| !identity_part.is_empty() && *identity_part != "(null)")
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        if !identity.is_empty() {
+            identity
+        } else if !self.description.is_empty() {
+            self.description.clone()
+        } else {
+            self.port_name.clone()
+        }
+    }
+
+    pub fn get_display_name(&self) -> String {
+        if self.model_name.is_empty() {
+            self.port_name.clone()
+        } else {
+            self.model_name.clone()
+        }
+    }
+
+    pub fn find_video_mode(&self, video_mode: &VideoMode) -> Option<&VideoMode> {
+        self.video_modes.iter()
+            .find(|advertised_video_mode| advertised_video_mode.is_same_mode(video_mode))
+    }
+
+    pub fn has_video_mode(&self, video_mode: &VideoMode) -> bool {
+        self.find_video_mode(video_mode).is_some()
+    }
+
+    // The backends don't report which mode Hyprland prefers, so the preferred mode is approximated by the native
+    // resolution of the panel, which is what Hyprland picks for most monitors.
+    pub fn get_video_mode_by_preset(&self, video_mode_preset: VideoModePreset) -> Option<VideoMode> {
+        match video_mode_preset {
+            VideoModePreset::Custom => None,
+            VideoModePreset::Preferred | VideoModePreset::HighResolution => self.video_modes.iter()
+                .max_by(|first_mode, second_mode| first_mode.get_pixel_count().cmp(&second_mode.get_pixel_count())
+                    .then(first_mode.refresh_rate.total_cmp(&second_mode.refresh_rate)))
+                .cloned(),
+            VideoModePreset::HighRefreshRate => self.video_modes.iter()
+                .max_by(|first_mode, second_mode| first_mode.refresh_rate.total_cmp(&second_mode.refresh_rate)
+                    .then(first_mode.get_pixel_count().cmp(&second_mode.get_pixel_count())))
+                .cloned(),
+        }
+    }
 }
\ No newline at end of file
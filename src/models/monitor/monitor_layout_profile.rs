@@ -0,0 +1,35 @@
+// A monitor layout profile stores the monitor configurations for one set of connected monitors, similar to the
+// profiles of kanshi. The configurations are keyed by the identity of the monitor instead of its port, because the
+// ports of a monitor can change between docks and reboots.
+
+use std::collections::{BTreeMap, BTreeSet};
+use serde::{Deserialize, Serialize};
+use crate::models::monitor::monitor_configuration::MonitorConfiguration;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonitorLayoutProfile {
+    pub name: String,
+    pub monitor_configurations: BTreeMap<String, MonitorConfiguration>,
+}
+
+impl MonitorLayoutProfile {
+    pub fn new(monitor_configurations: BTreeMap<String, MonitorConfiguration>) -> Self {
+        let name = monitor_configurations.values()
+            .map(|configuration| configuration.information.get_display_name())
+            .collect::<Vec<String>>()
+            .join(" + ");
+
+        Self {
+            name,
+            monitor_configurations
+        }
+    }
+
+    pub fn get_monitor_identities(&self) -> BTreeSet<String> {
+        self.monitor_configurations.keys().cloned().collect()
+    }
+
+    pub fn matches(&self, monitor_identities: &BTreeSet<String>) -> bool {
+        self.get_monitor_identities() == *monitor_identities
+    }
+}
\ No newline at end of file
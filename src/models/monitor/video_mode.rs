@@ -1,8 +1,97 @@
+use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+// Refresh rates only differing by this amount belong to the same mode, since the backends and config files round
+// them to a different number of decimals
+const REFRESH_RATE_TOLERANCE: f32 = 0.01;
+const REFRESH_RATE_DECIMALS: usize = 3;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct VideoMode {
-    pub refresh_rate: u32,
+    pub refresh_rate: f32,
     pub width_resolution: u32,
     pub height_resolution: u32,
+}
+
+impl Display for VideoMode {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}x{}@{}Hz", self.width_resolution, self.height_resolution, self.get_refresh_rate_text())
+    }
+}
+
+impl VideoMode {
+    pub fn get_pixel_count(&self) -> u64 {
+        self.width_resolution as u64 * self.height_resolution as u64
+    }
+
+    pub fn get_refresh_rate_text(&self) -> String {
+        let refresh_rate_text = format!("{:.*}", REFRESH_RATE_DECIMALS, self.refresh_rate);
+        refresh_rate_text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+
+    pub fn is_same_mode(&self, other_video_mode: &VideoMode) -> bool {
+        self.width_resolution == other_video_mode.width_resolution
+            && self.height_resolution == other_video_mode.height_resolution
+            && (self.refresh_rate - other_video_mode.refresh_rate).abs() < REFRESH_RATE_TOLERANCE
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VideoModePreset {
+    #[default]
+    Custom,
+    Preferred,
+    HighResolution,
+    HighRefreshRate,
+}
+
+impl From<String> for VideoModePreset {
+    fn from(enum_string: String) -> Self {
+        match enum_string.as_str() {
+            "Preferred" => VideoModePreset::Preferred,
+            "Highest resolution" => VideoModePreset::HighResolution,
+            "Highest refresh rate" => VideoModePreset::HighRefreshRate,
+            _ => VideoModePreset::Custom,
+        }
+    }
+}
+
+impl Display for VideoModePreset {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let enum_string = match self {
+            VideoModePreset::Custom => "Custom",
+            VideoModePreset::Preferred => "Preferred",
+            VideoModePreset::HighResolution => "Highest resolution",
+            VideoModePreset::HighRefreshRate => "Highest refresh rate",
+        };
+        write!(formatter, "{}", enum_string)
+    }
+}
+
+impl VideoModePreset {
+    pub fn get_preset_option_names() -> Vec<String> {
+        vec![
+            VideoModePreset::Preferred.to_string(),
+            VideoModePreset::HighResolution.to_string(),
+            VideoModePreset::HighRefreshRate.to_string(),
+        ]
+    }
+
+    pub fn get_hyprland_mode_name(&self) -> Option<&'static str> {
+        match self {
+            VideoModePreset::Custom => None,
+            VideoModePreset::Preferred => Some("preferred"),
+            VideoModePreset::HighResolution => Some("highres"),
+            VideoModePreset::HighRefreshRate => Some("highrr"),
+        }
+    }
+
+    pub fn from_hyprland_mode_name(mode_name: &str) -> Option<Self> {
+        match mode_name {
+            "preferred" => Some(VideoModePreset::Preferred),
+            "highres" => Some(VideoModePreset::HighResolution),
+            "highrr" => Some(VideoModePreset::HighRefreshRate),
+            _ => None,
+        }
+    }
 }
\ No newline at end of file
@@ -50,8 +50,9 @@ impl<'de> Deserialize<'de> for RGBAColor {
             where
                 E: de::Error,
             {
-                let rgba = RGBA::parse(value).expect("Cannot parse rgba string");
-                Ok(RGBAColor::new(rgba))
+                RGBA::parse(value)
+                    .map(RGBAColor::new)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
             }
         }
 
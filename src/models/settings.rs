@@ -4,4 +4,6 @@ pub mod keybind_settings;
 pub mod lockscreen_settings;
 pub mod program_settings;
 pub mod monitor_settings;
-pub mod input_settings;
\ No newline at end of file
+pub mod input_settings;
+pub mod backup_settings;
+pub mod config_file_settings;
\ No newline at end of file
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use crate::models::rgba_color::RGBAColor;
 
@@ -5,12 +6,15 @@ use crate::models::rgba_color::RGBAColor;
 #[serde(default)]
 pub struct AppearanceSettings {
     pub wallpaper_path: String,
+    pub monitor_wallpapers: BTreeMap<String, String>,
+    pub wallpaper_splash: bool,
+    pub disable_wallpaper_ipc: bool,
     pub cursor_size: u32,
     pub cursor_theme: String,
     pub force_default_wallpaper: bool,
     pub disable_hyprland_logo: bool,
-    pub inner_gab: f64,
-    pub outer_gab: f64,
+    pub inner_gap: f64,
+    pub outer_gap: f64,
     pub border_size: f64,
     pub active_border_color: RGBAColor,
     pub inactive_border_color: RGBAColor,
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_BACKUP_RETENTION_COUNT: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupSettings {
+    pub retention_count: usize,
+}
+
+impl Default for BackupSettings {
+    fn default() -> Self {
+        Self {
+            retention_count: DEFAULT_BACKUP_RETENTION_COUNT,
+        }
+    }
+}
\ No newline at end of file
@@ -1,35 +1,62 @@
 use serde::{Deserialize, Serialize};
 use crate::models::settings::appearance_settings::AppearanceSettings;
+use crate::models::settings::backup_settings::BackupSettings;
+use crate::models::settings::config_file_settings::ConfigFileSettings;
 use crate::models::settings::monitor_settings::MonitorSettings;
 use crate::models::settings::input_settings::InputSettings;
 use crate::models::settings::keybind_settings::KeyBindSettings;
 use crate::models::settings::lockscreen_settings::LockScreenSettings;
 use crate::models::settings::program_settings::ProgramSettings;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct HyprlandSettings {
+    pub schema_version: u32,
     pub program_settings: ProgramSettings,
     pub monitor_settings: MonitorSettings,
     pub appearance_settings: AppearanceSettings,
     pub input_settings: InputSettings,
     pub keybind_settings: KeyBindSettings,
     pub lockscreen_settings: LockScreenSettings,
+    pub backup_settings: BackupSettings,
+    pub config_file_settings: ConfigFileSettings,
 }
 
 impl HyprlandSettings {
     pub fn new(
         program_settings: ProgramSettings, monitor_settings: MonitorSettings,
         appearance_settings: AppearanceSettings, input_settings: InputSettings, 
-        keybind_settings: KeyBindSettings, lockscreen_settings: LockScreenSettings
+        keybind_settings: KeyBindSettings, lockscreen_settings: LockScreenSettings,
+        backup_settings: BackupSettings, config_file_settings: ConfigFileSettings
     ) -> Self {
         Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
             program_settings,
             monitor_settings,
             appearance_settings,
             input_settings,
             keybind_settings,
             lockscreen_settings,
+            backup_settings,
+            config_file_settings,
+        }
+    }
+}
+
+impl Default for HyprlandSettings {
+    fn default() -> Self {
+        Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            program_settings: Default::default(),
+            monitor_settings: Default::default(),
+            appearance_settings: Default::default(),
+            input_settings: Default::default(),
+            keybind_settings: Default::default(),
+            lockscreen_settings: Default::default(),
+            backup_settings: Default::default(),
+            config_file_settings: Default::default(),
         }
     }
 }
\ No newline at end of file
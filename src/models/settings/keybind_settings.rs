@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use crate::models::keybinds::custom_keybind::CustomKeybind;
 use crate::models::keybinds::key_bind_configuration::KeyBindConfiguration;
@@ -7,6 +7,6 @@ use crate::models::keybinds::system_keybind::SystemKeybind;
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct KeyBindSettings {
-    pub program_keybinds: HashMap<SystemKeybind, KeyBindConfiguration>,
-    pub custom_keybinds: HashMap<String, CustomKeybind>
+    pub program_keybinds: BTreeMap<SystemKeybind, KeyBindConfiguration>,
+    pub custom_keybinds: BTreeMap<String, CustomKeybind>
 }
\ No newline at end of file
@@ -25,16 +25,16 @@ pub struct LockScreenSettings {
     pub input_font_color: RGBAColor,
     pub input_placeholder_text: String,
     pub hide_input: bool,
-    pub input_x_position: u32,
-    pub input_y_position: u32,
+    pub input_x_position: i32,
+    pub input_y_position: i32,
     pub input_vertical_alignment: String,
     pub input_horizontal_alignment: String,
     pub display_text: String,
     pub display_text_color: RGBAColor,
     pub display_text_font_size: u32,
     pub display_text_font: String,
-    pub display_text_x_position: u32,
-    pub display_text_y_position: u32,
+    pub display_text_x_position: i32,
+    pub display_text_y_position: i32,
     pub display_text_vertical_alignment: String,
     pub display_text_horizontal_alignment: String,
 }
\ No newline at end of file
@@ -1,17 +1,20 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use crate::models::monitor::monitor_configuration::MonitorConfiguration;
+use crate::models::monitor::monitor_layout_profile::MonitorLayoutProfile;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct MonitorSettings {
-    pub monitor_configurations: HashMap<String, MonitorConfiguration>,
+    pub monitor_configurations: BTreeMap<String, MonitorConfiguration>,
+    pub layout_profiles: Vec<MonitorLayoutProfile>,
 }
 
 impl MonitorSettings {
-    pub fn new(monitor_configurations: HashMap<String, MonitorConfiguration>,) -> Self {
+    pub fn new(monitor_configurations: BTreeMap<String, MonitorConfiguration>,) -> Self {
         Self {
-            monitor_configurations
+            monitor_configurations,
+            layout_profiles: Vec::new(),
         }
     }
 }
\ No newline at end of file
@@ -1,15 +1,55 @@
-use std::collections::HashMap;
-use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{MapAccess, Visitor};
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ProgramSettings {
-    pub programs: HashMap<String, String>,
-    pub startup_programs: HashMap<String, String>,
+    pub programs: BTreeMap<String, String>,
+    // Startup programs are run in the order they are written, so they keep their order while still being stored
+    // as a mapping from program name to command
+    #[serde(serialize_with = "serialize_ordered_map", deserialize_with = "deserialize_ordered_map")]
+    pub startup_programs: Vec<(String, String)>,
 }
 
 impl ProgramSettings {
     pub fn new() -> Self {
         Self::default()
     }
+}
+
+fn serialize_ordered_map<S>(entries: &[(String, String)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_map(entries.iter().map(|(name, value)| (name, value)))
+}
+
+fn deserialize_ordered_map<'de, D>(deserializer: D) -> Result<Vec<(String, String)>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct OrderedMapVisitor;
+    impl<'de> Visitor<'de> for OrderedMapVisitor {
+        type Value = Vec<(String, String)>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a mapping from names to values")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Vec<(String, String)>, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut entries: Vec<(String, String)> = Vec::new();
+            while let Some((name, value)) = map.next_entry::<String, String>()? {
+                entries.retain(|(entry_name, _)| *entry_name != name);
+                entries.push((name, value));
+            }
+            Ok(entries)
+        }
+    }
+
+    deserializer.deserialize_map(OrderedMapVisitor)
 }
\ No newline at end of file
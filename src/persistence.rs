@@ -1,8 +1,27 @@
+pub mod settings_error;
 pub mod settings_reader;
 pub mod settings_writer;
 pub mod yaml_settings_writer;
 pub mod hyprland_settings_writer;
 pub mod yaml_settings_reader;
+pub mod yaml_settings_migrator;
 pub mod hyprlock_settings_writer;
 pub mod hyprpaper_settings_writer;
-mod hyprland_writer_utils;
\ No newline at end of file
+pub mod nix_settings_writer;
+pub mod hyprland_config_reader;
+pub mod hyprland_config_parser;
+pub mod hyprlock_config_reader;
+pub mod hyprpaper_config_reader;
+pub mod config_backup;
+pub mod config_diff;
+pub mod config_directory;
+pub mod config_renderer;
+pub mod config_snapshot;
+pub mod settings_path;
+pub mod settings_diagnostic;
+pub mod settings_validator;
+pub mod settings_profiles;
+mod hyprland_writer_utils;
+mod hyprland_reader_utils;
+mod hyprland_config_merger;
+mod nix_expression;
\ No newline at end of file
@@ -0,0 +1,239 @@
+// Every backup directory only stores the files that changed since the previous backup, so the snapshot of a file is
+// found in the newest directory up to the selected one. Removed backups hand their files over to the oldest kept one.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::persistence::hyprland_settings_writer::{
+    AUTOSTART_CONFIG_FILE, INPUT_CONFIG_FILE, KEYBINDS_CONFIG_FILE, LOOK_AND_FEEL_CONFIG_FILE, MONITORS_CONFIG_FILE
+};
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+use crate::persistence::settings_error::SettingsError;
+
+pub const BACKUP_DIRECTORY_NAME: &str = "hyprsettings-backups";
+pub const BACKUP_CONFIG_FILES: [&str; 9] = [
+    "hyprland.conf", "hyprlock.conf", "hyprpaper.conf", "hyprsettings.yaml",
+    AUTOSTART_CONFIG_FILE, MONITORS_CONFIG_FILE, LOOK_AND_FEEL_CONFIG_FILE, INPUT_CONFIG_FILE, KEYBINDS_CONFIG_FILE
+];
+
+const SECONDS_PER_DAY: u64 = 86400;
+const MILLISECONDS_PER_SECOND: u64 = 1000;
+
+static LAST_BACKUP_MILLISECONDS: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone)]
+pub struct ConfigBackupEntry {
+    pub name: String,
+    pub changed_files: Vec<String>,
+}
+
+impl ConfigBackupEntry {
+    pub fn get_display_time(&self) -> String {
+        match self.name.split_once('_') {
+            Some((date, time)) => format!("{} {} UTC", date, time.replace('-', ":")),
+            None => self.name.clone(),
+        }
+    }
+}
+
+pub struct ConfigBackup {
+    backup_directory: PathBuf,
+}
+
+impl ConfigBackup {
+    pub fn new() -> Self {
+        let mut milliseconds = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        milliseconds = milliseconds.max(LAST_BACKUP_MILLISECONDS.load(Ordering::Relaxed) + 1);
+
+        let mut backup_directory = Self::get_backups_directory().join(Self::create_timestamp(milliseconds));
+        while backup_directory.exists() {
+            milliseconds += 1;
+            backup_directory = Self::get_backups_directory().join(Self::create_timestamp(milliseconds));
+        }
+
+        LAST_BACKUP_MILLISECONDS.fetch_max(milliseconds, Ordering::Relaxed);
+        Self {
+            backup_directory,
+        }
+    }
+
+    pub fn get_backups_directory() -> PathBuf {
+        PathBuf::from(HyprlandWriterUtils::create_hyprland_config_path(BACKUP_DIRECTORY_NAME))
+    }
+
+    pub fn get_backup_directories() -> Vec<PathBuf> {
+        let Ok(backup_entries) = fs::read_dir(Self::get_backups_directory()) else {
+            return Vec::new();
+        };
+
+        let mut backup_directories: Vec<PathBuf> = backup_entries
+            .filter_map(|backup_entry| backup_entry.ok())
+            .map(|backup_entry| backup_entry.path())
+            .filter(|backup_path| backup_path.is_dir())
+            .collect();
+
+        backup_directories.sort();
+        backup_directories
+    }
+
+    pub fn get_backup_entries() -> Vec<ConfigBackupEntry> {
+        let backup_directories = Self::get_backup_directories();
+        backup_directories.iter()
+            .enumerate()
+            .rev()
+            .map(|(backup_index, backup_directory)| {
+                let changed_files = BACKUP_CONFIG_FILES.iter()
+                    .filter(|config_name| {
+                        let Ok(backup_content) = fs::read(backup_directory.join(config_name)) else {
+                            return false;
+                        };
+
+                        let previous_content = backup_directories[..backup_index].iter()
+                            .rev()
+                            .find_map(|previous_directory| fs::read(previous_directory.join(config_name)).ok());
+                        previous_content.is_none_or(|previous_content| previous_content != backup_content)
+                    })
+                    .map(|config_name| config_name.to_string())
+                    .collect();
+
+                ConfigBackupEntry {
+                    name: Self::get_backup_name(backup_directory),
+                    changed_files,
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_snapshot_content(backup_name: &str, config_name: &str) -> Option<String> {
+        Self::get_backup_directories().iter()
+            .rev()
+            .filter(|backup_directory| Self::get_backup_name(backup_directory).as_str() <= backup_name)
+            .find_map(|backup_directory| fs::read_to_string(backup_directory.join(config_name)).ok())
+    }
+
+    pub fn get_current_content(config_name: &str) -> String {
+        let config_file_path = HyprlandWriterUtils::create_hyprland_config_path(config_name);
+        fs::read_to_string(config_file_path).unwrap_or_default()
+    }
+
+    pub fn restore_backup(backup_name: &str) -> Result<(), SettingsError> {
+        let snapshot_contents: Vec<(&str, String)> = BACKUP_CONFIG_FILES.iter()
+            .filter_map(|config_name| {
+                Self::get_snapshot_content(backup_name, config_name)
+                    .map(|snapshot_content| (*config_name, snapshot_content))
+            })
+            .collect();
+
+        Self::new().backup_config_files()?;
+        for (config_name, snapshot_content) in snapshot_contents {
+            let config_file_path = HyprlandWriterUtils::create_hyprland_config_path(config_name);
+            HyprlandWriterUtils::write_content_atomically(config_file_path.as_str(), snapshot_content.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn backup_config_files(&self) -> Result<(), SettingsError> {
+        for config_name in BACKUP_CONFIG_FILES {
+            self.backup_config_file(config_name)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn backup_config_file(&self, config_name: &str) -> Result<(), SettingsError> {
+        let config_file_path = HyprlandWriterUtils::create_hyprland_config_path(config_name);
+        let Ok(config_content) = fs::read(config_file_path) else {
+            return Ok(());
+        };
+
+        if self.get_latest_backup_content(config_name).is_some_and(|backup_content| backup_content == config_content) {
+            return Ok(());
+        }
+
+        fs::create_dir_all(&self.backup_directory)
+            .map_err(|error| SettingsError::io(&self.backup_directory.to_string_lossy(), error))?;
+
+        let backup_file_path = self.backup_directory.join(config_name);
+        fs::write(&backup_file_path, config_content)
+            .map_err(|error| SettingsError::io(&backup_file_path.to_string_lossy(), error))
+    }
+
+    pub fn remove_old_backups(retention_count: usize) -> Result<(), SettingsError> {
+        let backup_directories = Self::get_backup_directories();
+        if backup_directories.len() <= retention_count {
+            return Ok(());
+        }
+
+        let removable_backup_count = backup_directories.len() - retention_count;
+        let (removable_backup_directories, kept_backup_directories) = backup_directories.split_at(removable_backup_count);
+        if let Some(oldest_backup_directory) = kept_backup_directories.first() {
+            for backup_directory in removable_backup_directories.iter().rev() {
+                Self::move_missing_files(backup_directory, oldest_backup_directory)?;
+            }
+        }
+
+        for backup_directory in removable_backup_directories {
+            fs::remove_dir_all(backup_directory)
+                .map_err(|error| SettingsError::io(&backup_directory.to_string_lossy(), error))?;
+        }
+
+        Ok(())
+    }
+
+    fn move_missing_files(source_directory: &Path, target_directory: &Path) -> Result<(), SettingsError> {
+        for config_name in BACKUP_CONFIG_FILES {
+            let source_file_path = source_directory.join(config_name);
+            let target_file_path = target_directory.join(config_name);
+            if !source_file_path.is_file() || target_file_path.exists() {
+                continue;
+            }
+
+            fs::rename(&source_file_path, &target_file_path)
+                .map_err(|error| SettingsError::io(&target_file_path.to_string_lossy(), error))?;
+        }
+
+        Ok(())
+    }
+
+    fn get_latest_backup_content(&self, config_name: &str) -> Option<Vec<u8>> {
+        Self::get_backup_directories().iter()
+            .rev()
+            .filter(|backup_directory| **backup_directory != self.backup_directory)
+            .find_map(|backup_directory| fs::read(backup_directory.join(config_name)).ok())
+    }
+
+    fn get_backup_name(backup_directory: &Path) -> String {
+        backup_directory.file_name()
+            .map(|backup_name| backup_name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    fn create_timestamp(milliseconds: u64) -> String {
+        let seconds = milliseconds / MILLISECONDS_PER_SECOND;
+        let (year, month, day) = Self::get_civil_date((seconds / SECONDS_PER_DAY) as i64);
+        let seconds_of_day = seconds % SECONDS_PER_DAY;
+
+        format!(
+            "{:04}-{:02}-{:02}_{:02}-{:02}-{:02}.{:03}",
+            year, month, day,
+            seconds_of_day / 3600, seconds_of_day % 3600 / 60, seconds_of_day % 60,
+            milliseconds % MILLISECONDS_PER_SECOND
+        )
+    }
+
+    fn get_civil_date(days_since_epoch: i64) -> (i64, i64, i64) {
+        let shifted_days = days_since_epoch + 719468;
+        let era = shifted_days.div_euclid(146097);
+        let day_of_era = shifted_days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        (year, month, day)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,127 @@
+// Line based diff of two config file contents. The common prefix and suffix are skipped first, so the
+// longest common subsequence table only covers the changed region of the files.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffLineKind {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub text: String,
+}
+
+pub struct ConfigDiff;
+
+impl ConfigDiff {
+    pub fn create_line_diff(old_content: &str, new_content: &str) -> Vec<DiffLine> {
+        let old_lines: Vec<&str> = old_content.lines().collect();
+        let new_lines: Vec<&str> = new_content.lines().collect();
+
+        let prefix_length = old_lines.iter()
+            .zip(new_lines.iter())
+            .take_while(|(old_line, new_line)| old_line == new_line)
+            .count();
+        let suffix_length = old_lines[prefix_length..].iter().rev()
+            .zip(new_lines[prefix_length..].iter().rev())
+            .take_while(|(old_line, new_line)| old_line == new_line)
+            .count();
+
+        let changed_old_lines = &old_lines[prefix_length..old_lines.len() - suffix_length];
+        let changed_new_lines = &new_lines[prefix_length..new_lines.len() - suffix_length];
+
+        let mut diff_lines = Vec::new();
+        diff_lines.extend(Self::create_diff_lines(&old_lines[..prefix_length], DiffLineKind::Unchanged));
+        diff_lines.extend(Self::create_changed_diff_lines(changed_old_lines, changed_new_lines));
+        diff_lines.extend(Self::create_diff_lines(&old_lines[old_lines.len() - suffix_length..], DiffLineKind::Unchanged));
+        diff_lines
+    }
+
+    pub fn has_changes(diff_lines: &[DiffLine]) -> bool {
+        diff_lines.iter().any(|diff_line| diff_line.kind != DiffLineKind::Unchanged)
+    }
+
+    pub fn create_diff_text(diff_lines: &[DiffLine], context_line_count: usize) -> String {
+        let changed_line_indices: Vec<usize> = diff_lines.iter()
+            .enumerate()
+            .filter(|(_, diff_line)| diff_line.kind != DiffLineKind::Unchanged)
+            .map(|(index, _)| index)
+            .collect();
+
+        let is_visible_line = |index: usize| {
+            changed_line_indices.iter()
+                .any(|changed_index| changed_index.abs_diff(index) <= context_line_count)
+        };
+
+        let mut diff_text_lines = Vec::new();
+        let mut is_skipping_lines = false;
+        for (index, diff_line) in diff_lines.iter().enumerate() {
+            if !is_visible_line(index) {
+                if !is_skipping_lines {
+                    diff_text_lines.push("  ...".to_string());
+                }
+                is_skipping_lines = true;
+                continue;
+            }
+
+            is_skipping_lines = false;
+            let line_prefix = match diff_line.kind {
+                DiffLineKind::Unchanged => " ",
+                DiffLineKind::Added => "+",
+                DiffLineKind::Removed => "-",
+            };
+            diff_text_lines.push(format!("{} {}", line_prefix, diff_line.text));
+        }
+
+        diff_text_lines.join("\n")
+    }
+
+    fn create_changed_diff_lines(old_lines: &[&str], new_lines: &[&str]) -> Vec<DiffLine> {
+        let mut common_lengths = vec![vec![0usize; new_lines.len() + 1]; old_lines.len() + 1];
+        for old_index in (0..old_lines.len()).rev() {
+            for new_index in (0..new_lines.len()).rev() {
+                common_lengths[old_index][new_index] = if old_lines[old_index] == new_lines[new_index] {
+                    common_lengths[old_index + 1][new_index + 1] + 1
+                } else {
+                    common_lengths[old_index + 1][new_index].max(common_lengths[old_index][new_index + 1])
+                };
+            }
+        }
+
+        let mut diff_lines = Vec::new();
+        let (mut old_index, mut new_index) = (0, 0);
+        while old_index < old_lines.len() && new_index < new_lines.len() {
+            if old_lines[old_index] == new_lines[new_index] {
+                diff_lines.push(Self::create_diff_line(old_lines[old_index], DiffLineKind::Unchanged));
+                old_index += 1;
+                new_index += 1;
+            } else if common_lengths[old_index + 1][new_index] >= common_lengths[old_index][new_index + 1] {
+                diff_lines.push(Self::create_diff_line(old_lines[old_index], DiffLineKind::Removed));
+                old_index += 1;
+            } else {
+                diff_lines.push(Self::create_diff_line(new_lines[new_index], DiffLineKind::Added));
+                new_index += 1;
+            }
+        }
+
+        diff_lines.extend(Self::create_diff_lines(&old_lines[old_index..], DiffLineKind::Removed));
+        diff_lines.extend(Self::create_diff_lines(&new_lines[new_index..], DiffLineKind::Added));
+        diff_lines
+    }
+
+    fn create_diff_lines(lines: &[&str], kind: DiffLineKind) -> Vec<DiffLine> {
+        lines.iter()
+            .map(|line| Self::create_diff_line(line, kind.clone()))
+            .collect()
+    }
+
+    fn create_diff_line(text: &str, kind: DiffLineKind) -> DiffLine {
+        DiffLine {
+            kind,
+            text: text.to_string(),
+        }
+    }
+}
\ No newline at end of file
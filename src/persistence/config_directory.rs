@@ -0,0 +1,56 @@
+// The config directory is resolved in the following order: the --config-dir command line option, the
+// HYPRSETTINGS_CONFIG_DIR environment variable, $XDG_CONFIG_HOME/hypr and finally $HOME/.config/hypr.
+// Relative paths of the option and HYPRSETTINGS_CONFIG_DIR are resolved against the working directory, while a relative
+// XDG_CONFIG_HOME is ignored as required by the XDG base directory specification.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+pub const CONFIG_DIRECTORY_VARIABLE: &str = "HYPRSETTINGS_CONFIG_DIR";
+pub const HYPRLAND_CONFIG_DIRECTORY_NAME: &str = "hypr";
+
+static CONFIG_DIRECTORY_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+pub struct ConfigDirectory;
+
+impl ConfigDirectory {
+    pub fn set_override(config_directory: PathBuf) {
+        let config_directory = std::path::absolute(&config_directory).unwrap_or(config_directory);
+        if let Ok(mut config_directory_override) = CONFIG_DIRECTORY_OVERRIDE.write() {
+            *config_directory_override = Some(config_directory);
+        }
+    }
+
+    pub fn get_path() -> PathBuf {
+        if let Ok(config_directory_override) = CONFIG_DIRECTORY_OVERRIDE.read()
+            && let Some(config_directory) = config_directory_override.as_ref()
+        {
+            return config_directory.clone();
+        }
+
+        if let Ok(config_directory) = env::var(CONFIG_DIRECTORY_VARIABLE)
+            && !config_directory.is_empty()
+        {
+            let config_directory = PathBuf::from(config_directory);
+            return std::path::absolute(&config_directory).unwrap_or(config_directory);
+        }
+
+        if let Some(xdg_config_home) = Self::get_absolute_variable_path("XDG_CONFIG_HOME") {
+            return xdg_config_home.join(HYPRLAND_CONFIG_DIRECTORY_NAME);
+        }
+
+        let home_path = env::var("HOME").unwrap_or_default();
+        Path::new(&home_path).join(".config").join(HYPRLAND_CONFIG_DIRECTORY_NAME)
+    }
+
+    fn get_absolute_variable_path(variable: &str) -> Option<PathBuf> {
+        let variable_value = env::var(variable).ok()?;
+        let variable_path = PathBuf::from(variable_value);
+        if variable_path.is_absolute() {
+            Some(variable_path)
+        } else {
+            None
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use crate::models::settings::hyprland_settings::HyprlandSettings;
+use crate::persistence::hyprland_settings_writer::HyprlandSettingsWriter;
+use crate::persistence::hyprlock_settings_writer::HyprlockSettingsWriter;
+use crate::persistence::hyprpaper_settings_writer::HyprpaperSettingsWriter;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_writer::{RenderedConfig, SettingsWriter};
+use crate::persistence::yaml_settings_writer::YamlSettingsWriter;
+
+pub struct ConfigRenderer;
+
+impl ConfigRenderer {
+    pub fn render_all_configs(settings: &HyprlandSettings) -> Result<Vec<RenderedConfig>, SettingsError> {
+        let mut yaml_settings_writer = YamlSettingsWriter::new();
+        yaml_settings_writer.serialize_settings(settings.clone())?;
+
+        let mut rendered_configs = yaml_settings_writer.render_configs()?;
+        rendered_configs.extend(Self::render_hyprland_configs(settings)?);
+        Ok(rendered_configs)
+    }
+
+    pub fn render_hyprland_configs(settings: &HyprlandSettings) -> Result<Vec<RenderedConfig>, SettingsError> {
+        let mut hyprland_settings_writer = HyprlandSettingsWriter::new();
+        hyprland_settings_writer.serialize_settings(settings.clone())?;
+
+        let mut hyprpaper_settings_writer = HyprpaperSettingsWriter::new();
+        hyprpaper_settings_writer.serialize_settings(settings.appearance_settings.clone())?;
+
+        let mut hyprlock_settings_writer = HyprlockSettingsWriter::new();
+        hyprlock_settings_writer.serialize_settings(settings.lockscreen_settings.clone())?;
+
+        let rendered_configs = vec![
+            hyprland_settings_writer.render_configs()?,
+            hyprpaper_settings_writer.render_configs()?,
+            hyprlock_settings_writer.render_configs()?,
+        ];
+
+        Ok(rendered_configs.into_iter().flatten().collect())
+    }
+}
\ No newline at end of file
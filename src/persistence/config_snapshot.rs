@@ -0,0 +1,39 @@
+// Remembers the managed config files as HyprSettings last read or wrote them, so edits made by other programs
+// can be told apart from the files written on save.
+
+use std::collections::BTreeMap;
+use std::fs;
+use crate::persistence::config_backup::BACKUP_CONFIG_FILES;
+use crate::persistence::config_directory::ConfigDirectory;
+
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSnapshot {
+    config_contents: BTreeMap<String, Option<String>>,
+}
+
+impl ConfigSnapshot {
+    pub fn capture() -> Self {
+        let config_contents = BACKUP_CONFIG_FILES.iter()
+            .map(|config_name| (config_name.to_string(), Self::read_config_content(config_name)))
+            .collect();
+
+        Self {
+            config_contents
+        }
+    }
+
+    pub fn is_managed_config(config_name: &str) -> bool {
+        BACKUP_CONFIG_FILES.contains(&config_name)
+    }
+
+    pub fn get_changed_configs(&self) -> Vec<String> {
+        self.config_contents.iter()
+            .filter(|(config_name, config_content)| Self::read_config_content(config_name) != **config_content)
+            .map(|(config_name, _)| config_name.clone())
+            .collect()
+    }
+
+    fn read_config_content(config_name: &str) -> Option<String> {
+        fs::read_to_string(ConfigDirectory::get_path().join(config_name)).ok()
+    }
+}
\ No newline at end of file
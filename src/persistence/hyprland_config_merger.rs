@@ -0,0 +1,295 @@
+// The merger keeps every line of an existing config file that HyprSettings does not manage.
+// Generated lines are placed inside a delimited managed block which replaces the previous block on each save.
+// Unmanaged lines that set a key also written by the managed block are commented out, so the generated value
+// stays the only active one and the original line can still be recovered by the user. Keys like bind or exec-once
+// can be set any number of times, so their lines are only commented out when the managed block repeats them exactly,
+// otherwise lines HyprSettings never imported would be disabled. Such lines are compared after substituting variables
+// and normalizing the modifier keys of keybinds, the same way the config reader imports them.
+// Sections like the label blocks of hyprlock can be declared any number of times, and their keys only belong to the
+// block they are declared in. Lines inside these sections are therefore never replaced, the managed block adds its
+// own blocks next to the ones of the user.
+
+use std::collections::HashSet;
+use crate::persistence::hyprland_config_parser::HyprlandConfigParser;
+use crate::persistence::hyprland_reader_utils::HyprlandReaderUtils;
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+
+pub const MANAGED_BLOCK_BEGIN: &str = "BEGIN HyprSettings managed block (changes inside this block are overwritten on save)";
+pub const MANAGED_BLOCK_END: &str = "END HyprSettings managed block";
+const REPLACED_LINE_PREFIX: &str = "Replaced by HyprSettings:";
+const SOURCE_KEY: &str = "source";
+const ARGUMENT_KEYS: [&str; 3] = ["env", "monitor", "wallpaper"];
+const MULTIPLE_VALUE_KEY_PREFIXES: [&str; 3] = ["bind", "exec", "preload"];
+const KEYBIND_KEY_PREFIX: &str = "bind";
+const REPEATABLE_SECTIONS: [&str; 7] = ["background", "device", "image", "input-field", "label", "shape", "windowrule"];
+
+pub struct HyprlandConfigMerger {
+    managed_keys: HashSet<String>,
+    managed_entries: HashSet<String>,
+    sections: Vec<String>,
+    variable_parser: HyprlandConfigParser,
+}
+
+impl HyprlandConfigMerger {
+    pub fn new() -> Self {
+        Self {
+            managed_keys: HashSet::new(),
+            managed_entries: HashSet::new(),
+            sections: Vec::new(),
+            variable_parser: HyprlandConfigParser::new(),
+        }
+    }
+
+    pub fn merge(&mut self, existing_content: &str, managed_lines: Vec<String>, config_directory: &str) -> Vec<String> {
+        self.variable_parser.parse_content(existing_content, config_directory);
+        self.variable_parser.parse_content(&managed_lines.join("\n"), config_directory);
+        self.collect_managed_keys(&managed_lines);
+
+        let mut merged_lines = Vec::new();
+        let mut managed_block_index = None;
+        let mut is_inside_managed_block = false;
+        for line in existing_content.lines() {
+            if Self::is_marker_line(line, MANAGED_BLOCK_BEGIN) {
+                is_inside_managed_block = true;
+                managed_block_index.get_or_insert(merged_lines.len());
+                continue;
+            }
+
+            if Self::is_marker_line(line, MANAGED_BLOCK_END) {
+                is_inside_managed_block = false;
+                continue;
+            }
+
+            if is_inside_managed_block {
+                continue;
+            }
+
+            let is_managed_line = self.get_line_entry(line)
+                .is_some_and(|line_entry| self.is_managed_entry(&line_entry));
+
+            if is_managed_line {
+                merged_lines.push(Self::create_replaced_line(line));
+            } else {
+                merged_lines.push(line.to_string());
+            }
+        }
+
+        let managed_block_index = managed_block_index.unwrap_or_else(|| {
+            if merged_lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                merged_lines.push(HyprlandWriterUtils::create_new_line());
+            }
+            merged_lines.len()
+        });
+
+        let managed_block = Self::create_managed_block(managed_lines);
+        merged_lines.splice(managed_block_index..managed_block_index, managed_block);
+        merged_lines
+    }
+
+    pub fn create_managed_block(managed_lines: Vec<String>) -> Vec<String> {
+        let mut managed_block = Vec::new();
+        managed_block.push(HyprlandWriterUtils::create_comment(MANAGED_BLOCK_BEGIN));
+        managed_block.extend(managed_lines);
+        managed_block.push(HyprlandWriterUtils::create_comment(MANAGED_BLOCK_END));
+        managed_block
+    }
+
+    fn collect_managed_keys(&mut self, managed_lines: &[String]) {
+        self.sections.clear();
+        for line in managed_lines {
+            match self.get_line_entry(line) {
+                Some(LineEntry::Single(line_key)) => {
+                    self.managed_keys.insert(line_key);
+                },
+                Some(LineEntry::Multiple(entry)) => {
+                    self.managed_entries.insert(entry);
+                },
+                None => {}
+            }
+        }
+        self.sections.clear();
+    }
+
+    fn is_managed_entry(&self, line_entry: &LineEntry) -> bool {
+        match line_entry {
+            LineEntry::Single(line_key) => self.managed_keys.contains(line_key),
+            LineEntry::Multiple(entry) => self.managed_entries.contains(entry),
+        }
+    }
+
+    fn get_line_entry(&mut self, line: &str) -> Option<LineEntry> {
+        let line = HyprlandConfigParser::strip_comment(line);
+        let line = line.trim();
+
+        if line == "}" {
+            self.sections.pop();
+            return None;
+        }
+
+        if let Some(section_name) = line.strip_suffix('{') {
+            self.sections.push(section_name.trim().to_string());
+            return None;
+        }
+
+        let (key, value) = line.split_once('=')?;
+        if self.sections.iter().any(|section| REPEATABLE_SECTIONS.contains(&section.as_str())) {
+            return None;
+        }
+
+        let key = key.trim();
+        let mut line_key = self.sections.clone();
+        line_key.push(key.to_string());
+        let line_key = line_key.join(":");
+
+        if MULTIPLE_VALUE_KEY_PREFIXES.iter().any(|key_prefix| key.starts_with(key_prefix)) {
+            let value = self.variable_parser.substitute_variables(value);
+            let mut arguments: Vec<String> = value.split(',')
+                .map(|argument| argument.trim().to_string())
+                .collect();
+
+            if key.starts_with(KEYBIND_KEY_PREFIX) && arguments.len() > 1 {
+                let mut modifier_keys = HyprlandReaderUtils::parse_modifier_keys(&arguments[0]);
+                modifier_keys.sort();
+                arguments[0] = modifier_keys.join(" ");
+                arguments[1] = arguments[1].to_uppercase();
+            }
+
+            // Dispatchers without argument may be written with or without a trailing comma
+            while arguments.len() > 1 && arguments.last().is_some_and(|argument| argument.is_empty()) {
+                arguments.pop();
+            }
+
+            return Some(LineEntry::Multiple(format!("{}={}", line_key, arguments.join(","))));
+        }
+
+        if ARGUMENT_KEYS.contains(&line_key.as_str()) {
+            let argument = value.split(',').next().unwrap_or_default().trim();
+            return Some(LineEntry::Single(format!("{}:{}", line_key, argument)));
+        }
+
+        if line_key == SOURCE_KEY {
+            return Some(LineEntry::Single(format!("{}:{}", SOURCE_KEY, value.trim())));
+        }
+
+        Some(LineEntry::Single(line_key))
+    }
+
+    fn is_marker_line(line: &str, marker: &str) -> bool {
+        line.trim() == HyprlandWriterUtils::create_comment(marker)
+    }
+
+    fn create_replaced_line(line: &str) -> String {
+        let indentation_length = line.len() - line.trim_start().len();
+        let (indentation, content) = line.split_at(indentation_length);
+        let replaced_line = format!("{} {}", REPLACED_LINE_PREFIX, content);
+        format!("{}{}", indentation, HyprlandWriterUtils::create_comment(&replaced_line))
+    }
+}
+
+enum LineEntry {
+    Single(String),
+    Multiple(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merge(existing_content: &str, managed_lines: &[&str]) -> Vec<String> {
+        let managed_lines = managed_lines.iter().map(|line| line.to_string()).collect();
+        HyprlandConfigMerger::new().merge(existing_content, managed_lines, "")
+    }
+
+    fn create_replaced(line: &str) -> String {
+        HyprlandConfigMerger::create_replaced_line(line)
+    }
+
+    #[test]
+    fn existing_managed_block_is_replaced_in_place() {
+        let existing_content = [
+            "$terminal = kitty",
+            &HyprlandWriterUtils::create_comment(MANAGED_BLOCK_BEGIN),
+            "general {",
+            "\tgaps_in = 5",
+            "}",
+            &HyprlandWriterUtils::create_comment(MANAGED_BLOCK_END),
+            "exec-once = waybar",
+        ].join("\n");
+
+        let merged_lines = merge(&existing_content, &["general {", "\tgaps_in = 10", "}"]);
+        let expected_lines = [
+            "$terminal = kitty",
+            &HyprlandWriterUtils::create_comment(MANAGED_BLOCK_BEGIN),
+            "general {",
+            "\tgaps_in = 10",
+            "}",
+            &HyprlandWriterUtils::create_comment(MANAGED_BLOCK_END),
+            "exec-once = waybar",
+        ];
+        assert_eq!(merged_lines, expected_lines);
+    }
+
+    #[test]
+    fn missing_managed_block_is_appended() {
+        let merged_lines = merge("$terminal = kitty", &["exec-once = $terminal"]);
+        let expected_lines = [
+            "$terminal = kitty",
+            "",
+            &HyprlandWriterUtils::create_comment(MANAGED_BLOCK_BEGIN),
+            "exec-once = $terminal",
+            &HyprlandWriterUtils::create_comment(MANAGED_BLOCK_END),
+        ];
+        assert_eq!(merged_lines, expected_lines);
+    }
+
+    #[test]
+    fn conflicting_unmanaged_line_is_commented_out() {
+        let existing_content = "general {\n    gaps_in = 5\n    border_size = 2\n}\nmonitor = DP-1, preferred, auto, 1";
+        let merged_lines = merge(existing_content, &[
+            "general {", "\tgaps_in = 10", "}", "monitor = DP-1, 1920x1080@60, 0x0, 1"
+        ]);
+
+        assert_eq!(merged_lines[1], create_replaced("    gaps_in = 5"));
+        assert_eq!(merged_lines[2], "    border_size = 2");
+        assert_eq!(merged_lines[4], create_replaced("monitor = DP-1, preferred, auto, 1"));
+    }
+
+    #[test]
+    fn repeated_keys_are_only_replaced_by_identical_entries() {
+        let existing_content = [
+            "$mainMod = SUPER",
+            "bind = $mainMod SHIFT, q, killactive,",
+            "bind = SUPER, E, exec, nautilus",
+            "exec-once = waybar",
+            "exec-once = dunst",
+        ].join("\n");
+
+        let merged_lines = merge(&existing_content, &["bind = SHIFT SUPER, Q, killactive", "exec-once = waybar"]);
+        assert_eq!(merged_lines[1], create_replaced("bind = $mainMod SHIFT, q, killactive,"));
+        assert_eq!(merged_lines[2], "bind = SUPER, E, exec, nautilus");
+        assert_eq!(merged_lines[3], create_replaced("exec-once = waybar"));
+        assert_eq!(merged_lines[4], "exec-once = dunst");
+    }
+
+    #[test]
+    fn repeatable_sections_are_kept() {
+        let existing_content = [
+            "general {",
+            "    grace = 5",
+            "}",
+            "label {",
+            "    text = $TIME",
+            "    font_size = 64",
+            "}",
+            "label {",
+            "    text = Hello",
+            "}",
+        ].join("\n");
+
+        let merged_lines = merge(&existing_content, &[
+            "general {", "\tgrace = 0", "}", "label {", "\ttext = Welcome", "\tfont_size = 24", "}"
+        ]);
+        assert_eq!(merged_lines[1], create_replaced("    grace = 5"));
+        assert_eq!(merged_lines[3..10], existing_content.lines().skip(3).collect::<Vec<_>>()[..]);
+    }
+}
\ No newline at end of file
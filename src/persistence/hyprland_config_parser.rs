@@ -0,0 +1,201 @@
+// This parser tokenizes the config grammar shared by hyprland, hyprlock and hyprpaper.
+// Nested sections are flattened into colon separated keys (e.g. "decoration:blur:size"), variables are
+// substituted in place and sourced files are parsed recursively as if their lines were part of the parent file.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::persistence::hyprland_writer_utils::COMMENT_CHARACTER;
+use crate::persistence::settings_error::SettingsError;
+
+const SECTION_SEPARATOR: char = ':';
+const VARIABLE_CHARACTER: char = '$';
+const SOURCE_KEYWORD: &str = "source";
+const MAX_SOURCE_DEPTH: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct HyprlandConfigEntry {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug)]
+pub struct HyprlandConfigParser {
+    variables: HashMap<String, String>,
+    entries: Vec<HyprlandConfigEntry>,
+    sections: Vec<String>,
+}
+
+impl HyprlandConfigParser {
+    pub fn new() -> Self {
+        Self {
+            variables: HashMap::new(),
+            entries: Vec::new(),
+            sections: Vec::new(),
+        }
+    }
+
+    pub fn parse_file(&mut self, file_path: &str) -> Result<(), SettingsError> {
+        let file_content = fs::read_to_string(file_path)
+            .map_err(|error| SettingsError::io(file_path, error))?;
+        let config_directory = Self::get_parent_directory(file_path);
+        self.parse_lines(&file_content, &config_directory, 0);
+        Ok(())
+    }
+
+    pub fn parse_content(&mut self, content: &str, config_directory: &str) {
+        self.parse_lines(content, &PathBuf::from(config_directory), 0);
+    }
+
+    pub fn get_entries(&self) -> &Vec<HyprlandConfigEntry> {
+        &self.entries
+    }
+
+    pub fn get_value(&self, key: &str) -> Option<String> {
+        self.entries.iter()
+            .rev()
+            .find(|entry| entry.key == key)
+            .map(|entry| entry.value.clone())
+    }
+
+    pub fn get_values(&self, key: &str) -> Vec<String> {
+        self.entries.iter()
+            .filter(|entry| entry.key == key)
+            .map(|entry| entry.value.clone())
+            .collect()
+    }
+
+    pub fn get_variable(&self, name: &str) -> Option<String> {
+        let variable_name = name.trim_start_matches(VARIABLE_CHARACTER);
+        self.variables.get(variable_name).cloned()
+    }
+
+    fn parse_lines(&mut self, content: &str, config_directory: &Path, source_depth: usize) {
+        for line in content.lines() {
+            let line = Self::strip_comment(line);
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line == "}" {
+                self.sections.pop();
+                continue;
+            }
+
+            if let Some(section_name) = line.strip_suffix('{') {
+                self.sections.push(section_name.trim().to_string());
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+
+            let key = key.trim();
+            let value = value.trim();
+
+            if let Some(variable_name) = key.strip_prefix(VARIABLE_CHARACTER) {
+                let variable_value = self.substitute_variables(value);
+                self.variables.insert(variable_name.to_string(), variable_value);
+                continue;
+            }
+
+            let value = self.substitute_variables(value);
+            if key == SOURCE_KEYWORD && self.sections.is_empty() {
+                self.parse_source_file(&value, config_directory, source_depth);
+                continue;
+            }
+
+            self.entries.push(HyprlandConfigEntry {
+                key: self.create_full_key(key),
+                value,
+            });
+        }
+    }
+
+    fn parse_source_file(&mut self, source_path: &str, config_directory: &Path, source_depth: usize) {
+        if source_depth >= MAX_SOURCE_DEPTH {
+            return;
+        }
+
+        let full_source_path = Self::expand_path(source_path, config_directory);
+        if let Ok(source_content) = fs::read_to_string(&full_source_path) {
+            let source_directory = full_source_path.parent()
+                .map(|directory| directory.to_path_buf())
+                .unwrap_or(config_directory.to_path_buf());
+
+            let parent_sections = std::mem::take(&mut self.sections);
+            self.parse_lines(&source_content, &source_directory, source_depth + 1);
+            self.sections = parent_sections;
+        }
+    }
+
+    fn create_full_key(&self, key: &str) -> String {
+        if self.sections.is_empty() {
+            return key.to_string();
+        }
+
+        let mut full_key = self.sections.join(&SECTION_SEPARATOR.to_string());
+        full_key.push(SECTION_SEPARATOR);
+        full_key.push_str(key);
+        full_key
+    }
+
+    pub fn substitute_variables(&self, value: &str) -> String {
+        if !value.contains(VARIABLE_CHARACTER) {
+            return value.to_string();
+        }
+
+        let mut variable_names: Vec<&String> = self.variables.keys().collect();
+        variable_names.sort_by_key(|variable_name| std::cmp::Reverse(variable_name.len()));
+
+        let mut substituted_value = value.to_string();
+        for variable_name in variable_names {
+            let variable = format!("{}{}", VARIABLE_CHARACTER, variable_name);
+            substituted_value = substituted_value.replace(&variable, &self.variables[variable_name]);
+        }
+
+        substituted_value
+    }
+
+    pub fn strip_comment(line: &str) -> String {
+        let mut stripped_line = String::new();
+        let mut characters = line.chars().peekable();
+        while let Some(character) = characters.next() {
+            if character == COMMENT_CHARACTER {
+                if characters.peek() == Some(&COMMENT_CHARACTER) {
+                    stripped_line.push(COMMENT_CHARACTER);
+                    characters.next();
+                    continue;
+                }
+
+                break;
+            }
+
+            stripped_line.push(character);
+        }
+
+        stripped_line
+    }
+
+    fn expand_path(path: &str, config_directory: &Path) -> PathBuf {
+        if let Some(home_relative_path) = path.strip_prefix("~/") {
+            let home_path = std::env::var("HOME").unwrap_or("".to_string());
+            return Path::new(&home_path).join(home_relative_path);
+        }
+
+        let path = Path::new(path);
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            config_directory.join(path)
+        }
+    }
+
+    fn get_parent_directory(file_path: &str) -> PathBuf {
+        Path::new(file_path).parent()
+            .map(|directory| directory.to_path_buf())
+            .unwrap_or_default()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,451 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use crate::math::vector::Vector;
+use crate::models::keybinds::custom_keybind::{CustomKeybind, DEFAULT_BIND_TYPE};
+use crate::models::keybinds::key_bind_configuration::KeyBindConfiguration;
+use crate::models::keybinds::system_keybind::SystemKeybind;
+use crate::models::monitor::monitor_configuration::{MonitorConfiguration, MonitorOrientation};
+use crate::models::monitor::video_mode::{VideoMode, VideoModePreset};
+use crate::models::settings::hyprland_settings::HyprlandSettings;
+use crate::persistence::hyprland_config_parser::HyprlandConfigParser;
+use crate::persistence::hyprland_reader_utils::HyprlandReaderUtils;
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_reader::SettingsReader;
+use crate::providers::module_provider::{
+    FILE_MANAGER_ENTRY, NOTIFICATION_HANDLER_ENTRY, QUICK_SEARCH_ENTRY, VIRTUAL_TERMINAL_ENTRY
+};
+
+const PROGRAM_VARIABLES: [(&str, &str); 5] = [
+    ("terminal", VIRTUAL_TERMINAL_ENTRY),
+    ("fileManager", FILE_MANAGER_ENTRY),
+    ("quickSearch", QUICK_SEARCH_ENTRY),
+    ("menu", QUICK_SEARCH_ENTRY),
+    ("notifications", NOTIFICATION_HANDLER_ENTRY),
+];
+
+pub struct HyprlandConfigReader {
+    parser: HyprlandConfigParser,
+    deserialized_settings: HyprlandSettings,
+}
+
+impl SettingsReader<HyprlandSettings> for HyprlandConfigReader {
+    fn read_from_config(&mut self) -> Result<(), SettingsError> {
+        let hyprland_config_file = HyprlandWriterUtils::create_hyprland_config_path("hyprland.conf");
+        self.parser.parse_file(hyprland_config_file.as_str())
+    }
+
+    fn deserialize_settings(&mut self) -> HyprlandSettings {
+        self.deserialize_program_settings();
+        self.deserialize_monitor_settings();
+        self.deserialize_appearance_settings();
+        self.deserialize_input_settings();
+        self.deserialize_keybind_settings();
+        self.deserialized_settings.clone()
+    }
+}
+
+impl HyprlandConfigReader {
+    pub fn new(base_settings: HyprlandSettings) -> Self {
+        Self {
+            parser: HyprlandConfigParser::new(),
+            deserialized_settings: base_settings,
+        }
+    }
+
+    pub fn config_file_exists() -> bool {
+        let hyprland_config_file = HyprlandWriterUtils::create_hyprland_config_path("hyprland.conf");
+        fs::exists(hyprland_config_file).unwrap_or(false)
+    }
+
+    fn deserialize_program_settings(&mut self) {
+        let program_settings = &mut self.deserialized_settings.program_settings;
+        for (variable_name, program_entry) in PROGRAM_VARIABLES {
+            if let Some(program_command) = self.parser.get_variable(variable_name) {
+                program_settings.programs.insert(program_entry.to_string(), program_command);
+            }
+        }
+
+        let startup_programs: Vec<(String, String)> = self.parser.get_values("exec-once")
+            .into_iter()
+            .map(|startup_command| {
+                let program_entry = program_settings.programs.iter()
+                    .find(|(program_name, program_command)| {
+                        Self::is_program_variable_entry(program_name) && **program_command == startup_command
+                    })
+                    .map(|(program_name, _)| program_name.clone());
+
+                let program_name = match program_entry {
+                    Some(program_name) => program_name,
+                    None => Self::get_program_name(&startup_command),
+                };
+
+                (program_name, startup_command)
+            })
+            .collect();
+
+        program_settings.startup_programs = Self::name_entries(
+            program_settings.startup_programs.clone(), startup_programs,
+            |previous_command, command| previous_command == command
+        );
+    }
+
+    fn deserialize_monitor_settings(&mut self) {
+        let monitor_entries = self.parser.get_values("monitor");
+        if monitor_entries.is_empty() {
+            return;
+        }
+
+        let previous_configurations = self.deserialized_settings.monitor_settings.monitor_configurations.clone();
+        let mut monitor_configurations = BTreeMap::new();
+        for monitor_entry in monitor_entries {
+            if let Some((monitor_port, configuration)) = Self::parse_monitor_entry(&monitor_entry, &previous_configurations) {
+                monitor_configurations.insert(monitor_port, configuration);
+            }
+        }
+
+        if !monitor_configurations.is_empty() {
+            self.deserialized_settings.monitor_settings.monitor_configurations = monitor_configurations;
+        }
+    }
+
+    fn deserialize_appearance_settings(&mut self) {
+        let parser = &self.parser;
+        let settings = &mut self.deserialized_settings.appearance_settings;
+
+        for environment_entry in parser.get_values("env") {
+            if let Some((variable, value)) = HyprlandReaderUtils::parse_environment_variable(&environment_entry) {
+                match variable.as_str() {
+                    "XCURSOR_SIZE" => HyprlandReaderUtils::apply_number(&mut settings.cursor_size, &value),
+                    "XCURSOR_THEME" => settings.cursor_theme = value,
+                    _ => {}
+                }
+            }
+        }
+
+        HyprlandReaderUtils::apply_parsed_number(parser, "general:gaps_in", &mut settings.inner_gap);
+        HyprlandReaderUtils::apply_parsed_number(parser, "general:gaps_out", &mut settings.outer_gap);
+        HyprlandReaderUtils::apply_parsed_number(parser, "general:border_size", &mut settings.border_size);
+        HyprlandReaderUtils::apply_parsed_color(parser, "general:col.active_border", &mut settings.active_border_color);
+        HyprlandReaderUtils::apply_parsed_color(parser, "general:col.inactive_border", &mut settings.inactive_border_color);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "general:resize_on_border", &mut settings.resize_on_border);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "general:allow_tearing", &mut settings.allow_tearing);
+        HyprlandReaderUtils::apply_parsed_string(parser, "general:layout", &mut settings.layout);
+
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:rounding", &mut settings.rounding);
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:rounding_power", &mut settings.rounding_power);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "decoration:dim_inactive", &mut settings.dim_inactive);
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:active_opacity", &mut settings.active_opacity);
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:inactive_opacity", &mut settings.inactive_opacity);
+
+        HyprlandReaderUtils::apply_parsed_bool(parser, "decoration:shadow:enabled", &mut settings.active_shadow);
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:shadow:range", &mut settings.shadow_range);
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:shadow:render_power", &mut settings.shadow_render_power);
+        HyprlandReaderUtils::apply_parsed_color(parser, "decoration:shadow:color", &mut settings.shadow_color);
+
+        HyprlandReaderUtils::apply_parsed_bool(parser, "decoration:blur:enabled", &mut settings.active_blur);
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:blur:size", &mut settings.blur_size);
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:blur:passes", &mut settings.blur_passes);
+        HyprlandReaderUtils::apply_parsed_number(parser, "decoration:blur:vibrancy", &mut settings.blur_vibrancy);
+
+        HyprlandReaderUtils::apply_parsed_bool(parser, "dwindle:pseudotile", &mut settings.pseudo_tiling);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "dwindle:preserve_split", &mut settings.split_preservation);
+        HyprlandReaderUtils::apply_parsed_string(parser, "master:new_status", &mut settings.master_status);
+
+        HyprlandReaderUtils::apply_parsed_bool(parser, "misc:force_default_wallpaper", &mut settings.force_default_wallpaper);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "misc:disable_hyprland_logo", &mut settings.disable_hyprland_logo);
+    }
+
+    fn deserialize_input_settings(&mut self) {
+        let parser = &self.parser;
+        let settings = &mut self.deserialized_settings.input_settings;
+
+        HyprlandReaderUtils::apply_parsed_string(parser, "input:kb_layout", &mut settings.keyboard_layout);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "input:numlock_by_default", &mut settings.numlock_enabled);
+        HyprlandReaderUtils::apply_parsed_number(parser, "input:repeat_rate", &mut settings.keyboard_repeat_rate);
+        HyprlandReaderUtils::apply_parsed_number(parser, "input:repeat_delay", &mut settings.keyboard_repeat_delay);
+        HyprlandReaderUtils::apply_parsed_number(parser, "input:sensitivity", &mut settings.mouse_sensitivity);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "input:left_handed", &mut settings.mouse_left_handed);
+        HyprlandReaderUtils::apply_parsed_number(parser, "input:scroll_factor", &mut settings.mouse_scroll_factor);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "input:natural_scroll", &mut settings.mouse_natural_scroll);
+
+        HyprlandReaderUtils::apply_parsed_string(parser, "input:tablet:output", &mut settings.tablet_monitor);
+        if let Some(transform) = parser.get_value("input:tablet:transform")
+            && let Some(orientation_code) = HyprlandReaderUtils::parse_number::<u8>(&transform)
+        {
+            settings.tablet_orientation = MonitorOrientation::from_hyprland_orientation_code(orientation_code);
+        }
+
+        HyprlandReaderUtils::apply_parsed_pair(
+            parser, "input:tablet:region_position",
+            &mut settings.tablet_region_x, &mut settings.tablet_region_y
+        );
+        HyprlandReaderUtils::apply_parsed_pair(
+            parser, "input:tablet:region_size",
+            &mut settings.tablet_region_width, &mut settings.tablet_region_height
+        );
+        HyprlandReaderUtils::apply_parsed_bool(parser, "input:tablet:relative_input", &mut settings.tablet_relative_input);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "input:tablet:left_handed", &mut settings.tablet_left_handed);
+        HyprlandReaderUtils::apply_parsed_pair(
+            parser, "input:tablet:active_area_size",
+            &mut settings.tablet_active_width, &mut settings.tablet_active_height
+        );
+        HyprlandReaderUtils::apply_parsed_pair(
+            parser, "input:tablet:active_area_position",
+            &mut settings.tablet_active_x, &mut settings.tablet_active_y
+        );
+    }
+
+    fn deserialize_keybind_settings(&mut self) {
+        // Keybinds can carry flags as suffix of the keyword, e.g. bindl or binde
+        let keybind_entries: Vec<(String, String)> = self.parser.get_entries()
+            .iter()
+            .filter(|entry| Self::is_keybind_key(&entry.key))
+            .map(|entry| (entry.key.clone(), entry.value.clone()))
+            .collect();
+        let program_commands = self.deserialized_settings.program_settings.programs.clone();
+        let keybind_settings = &mut self.deserialized_settings.keybind_settings;
+        if !keybind_entries.is_empty() {
+            keybind_settings.program_keybinds.clear();
+        }
+
+        let mut custom_keybinds = Vec::new();
+
+        for (bind_type, keybind_entry) in keybind_entries {
+            let mut arguments = keybind_entry.splitn(4, ',').map(|argument| argument.trim());
+            let modifier_keys = arguments.next().unwrap_or("");
+            let key = arguments.next().unwrap_or("");
+            let dispatcher = arguments.next().unwrap_or("");
+            let dispatcher_argument = arguments.next().unwrap_or("");
+
+            let keybind_configuration = Self::create_keybind_configuration(modifier_keys, key);
+            let is_default_bind_type = bind_type == DEFAULT_BIND_TYPE;
+            if dispatcher == "exec" {
+                // Program keybinds are always written as plain binds, flagged ones stay custom keybinds
+                let program_entry = program_commands.iter()
+                    .find(|(program_name, program_command)| {
+                        Self::is_program_variable_entry(program_name) && *program_command == dispatcher_argument
+                    })
+                    .and_then(|(program_name, _)| Self::get_program_system_keybind(program_name))
+                    .filter(|_| is_default_bind_type);
+
+                if let Some(system_keybind) = program_entry {
+                    keybind_settings.program_keybinds.insert(system_keybind, keybind_configuration);
+                } else {
+                    let command = dispatcher_argument.to_string();
+                    let mut custom_keybind = CustomKeybind::new(command.clone(), keybind_configuration);
+                    custom_keybind.set_bind_type(bind_type);
+                    custom_keybinds.push((command, custom_keybind));
+                }
+
+                continue;
+            }
+
+            if !is_default_bind_type {
+                continue;
+            }
+
+            if let Some(system_keybind) = Self::get_action_system_keybind(dispatcher, dispatcher_argument) {
+                keybind_settings.program_keybinds.insert(system_keybind, keybind_configuration);
+            }
+        }
+
+        let previous_custom_keybinds = keybind_settings.custom_keybinds.clone().into_iter().collect();
+        keybind_settings.custom_keybinds = Self::name_entries(
+            previous_custom_keybinds, custom_keybinds, |previous_keybind, keybind| {
+                previous_keybind.command == keybind.command && previous_keybind.bind_type == keybind.bind_type
+            }
+        ).into_iter().collect();
+    }
+
+    fn parse_monitor_entry(
+        monitor_entry: &str, previous_configurations: &BTreeMap<String, MonitorConfiguration>
+    ) -> Option<(String, MonitorConfiguration)> {
+        let arguments: Vec<&str> = monitor_entry.split(',')
+            .map(|argument| argument.trim())
+            .collect();
+
+        let monitor_port = arguments.first()?.to_string();
+        if monitor_port.is_empty() {
+            return None;
+        }
+
+        let mut configuration = previous_configurations.get(&monitor_port)
+            .cloned()
+            .unwrap_or_default();
+        configuration.information.port_name = monitor_port.clone();
+
+        let video_mode_argument = arguments.get(1).copied().unwrap_or("");
+        if video_mode_argument == "disable" || video_mode_argument == "disabled" {
+            configuration.enabled = false;
+            return Some((monitor_port, configuration));
+        }
+
+        configuration.enabled = true;
+        if let Some(video_mode_preset) = VideoModePreset::from_hyprland_mode_name(video_mode_argument) {
+            configuration.set_video_mode_preset(video_mode_preset);
+        } else if let Some(video_mode) = Self::parse_video_mode(video_mode_argument) {
+            configuration.video_mode_preset = VideoModePreset::Custom;
+            configuration.video_mode = video_mode;
+        }
+
+        if configuration.video_mode.width_resolution == 0 {
+            configuration.video_mode = configuration.information.max_video_mode.clone();
+        }
+
+        if let Some((offset_x, offset_y)) = arguments.get(2)
+            .and_then(|offset| HyprlandReaderUtils::parse_pair::<f64>(offset))
+        {
+            configuration.offset = Vector::new(offset_x, offset_y);
+        }
+
+        configuration.resolution_scale = arguments.get(3)
+            .and_then(|scale| HyprlandReaderUtils::parse_number::<f32>(scale))
+            .unwrap_or(1.0);
+
+        let mut argument_index = 4;
+        while argument_index + 1 < arguments.len() {
+            if arguments[argument_index] == "transform"
+                && let Some(orientation_code) = HyprlandReaderUtils::parse_number::<u8>(arguments[argument_index + 1])
+            {
+                configuration.orientation = MonitorOrientation::from_hyprland_orientation_code(orientation_code);
+            }
+
+            argument_index += 2;
+        }
+
+        Some((monitor_port, configuration))
+    }
+
+    fn parse_video_mode(video_mode_argument: &str) -> Option<VideoMode> {
+        let (resolution, refresh_rate) = match video_mode_argument.split_once('@') {
+            Some((resolution, refresh_rate)) => (resolution, Some(refresh_rate)),
+            None => (video_mode_argument, None),
+        };
+
+        let (width_resolution, height_resolution) = resolution.split_once('x')?;
+        let refresh_rate = refresh_rate
+            .and_then(|refresh_rate| refresh_rate.trim_end_matches("Hz").parse::<f32>().ok())
+            .unwrap_or(60.0);
+
+        Some(VideoMode {
+            width_resolution: width_resolution.parse::<u32>().ok()?,
+            height_resolution: height_resolution.parse::<u32>().ok()?,
+            refresh_rate,
+        })
+    }
+
+    fn create_keybind_configuration(modifier_keys: &str, key: &str) -> KeyBindConfiguration {
+        let mut keybind_configuration = KeyBindConfiguration::new();
+        for modifier_key_name in HyprlandReaderUtils::parse_modifier_keys(modifier_keys) {
+            keybind_configuration.append_key(modifier_key_name);
+        }
+
+        if !key.is_empty() {
+            keybind_configuration.append_key(key.to_uppercase());
+        }
+
+        keybind_configuration
+    }
+
+    fn get_program_system_keybind(program_name: &str) -> Option<SystemKeybind> {
+        match program_name {
+            VIRTUAL_TERMINAL_ENTRY => Some(SystemKeybind::Terminal),
+            FILE_MANAGER_ENTRY => Some(SystemKeybind::FileManager),
+            QUICK_SEARCH_ENTRY => Some(SystemKeybind::RunProgram),
+            _ => None,
+        }
+    }
+
+    fn get_action_system_keybind(dispatcher: &str, argument: &str) -> Option<SystemKeybind> {
+        let system_keybind = match (dispatcher, argument) {
+            ("killactive", _) => SystemKeybind::CloseWindow,
+            ("exit", _) => SystemKeybind::ExitHyprland,
+            ("togglefloating", _) => SystemKeybind::ToggleFloatingWindow,
+            ("pseudo", _) => SystemKeybind::Pseudo,
+            ("togglesplit", _) => SystemKeybind::SplitWindow,
+            ("movefocus", "l") => SystemKeybind::FocusLeftWindow,
+            ("movefocus", "r") => SystemKeybind::FocusRightWindow,
+            ("movefocus", "u") => SystemKeybind::FocusTopWindow,
+            ("movefocus", "d") => SystemKeybind::FocusBottomWindow,
+            ("workspace", "1") => SystemKeybind::SwitchWorkspaceOne,
+            ("workspace", "2") => SystemKeybind::SwitchWorkspaceTwo,
+            ("workspace", "3") => SystemKeybind::SwitchWorkspaceThree,
+            ("workspace", "4") => SystemKeybind::SwitchWorkspaceFour,
+            ("workspace", "5") => SystemKeybind::SwitchWorkspaceFive,
+            ("workspace", "6") => SystemKeybind::SwitchWorkspaceSix,
+            ("workspace", "7") => SystemKeybind::SwitchWorkspaceSeven,
+            ("workspace", "8") => SystemKeybind::SwitchWorkspaceEight,
+            ("workspace", "9") => SystemKeybind::SwitchWorkspaceNine,
+            ("workspace", "0") | ("workspace", "10") => SystemKeybind::SwitchWorkspaceZero,
+            ("movetoworkspace", "1") => SystemKeybind::MoveWorkspaceOne,
+            ("movetoworkspace", "2") => SystemKeybind::MoveWorkspaceTwo,
+            ("movetoworkspace", "3") => SystemKeybind::MoveWorkspaceThree,
+            ("movetoworkspace", "4") => SystemKeybind::MoveWorkspaceFour,
+            ("movetoworkspace", "5") => SystemKeybind::MoveWorkspaceFive,
+            ("movetoworkspace", "6") => SystemKeybind::MoveWorkspaceSix,
+            ("movetoworkspace", "7") => SystemKeybind::MoveWorkspaceSeven,
+            ("movetoworkspace", "8") => SystemKeybind::MoveWorkspaceEight,
+            ("movetoworkspace", "9") => SystemKeybind::MoveWorkspaceNine,
+            ("movetoworkspace", "0") | ("movetoworkspace", "10") => SystemKeybind::MoveWorkspaceZero,
+            _ => return None,
+        };
+
+        Some(system_keybind)
+    }
+
+    fn is_program_variable_entry(program_name: &str) -> bool {
+        PROGRAM_VARIABLES.iter().any(|(_, program_entry)| *program_entry == program_name)
+    }
+
+    fn is_keybind_key(key: &str) -> bool {
+        key.strip_prefix(DEFAULT_BIND_TYPE)
+            .is_some_and(|flags| flags.chars().all(|flag| flag.is_ascii_lowercase()))
+    }
+
+    // Parsed entries only carry a name derived from their command, so they keep the name of the previous entry with
+    // the same command. Only new entries get the derived name, which is numbered when it is already taken, while
+    // previous entries without a parsed counterpart are dropped.
+    fn name_entries<T>(
+        previous_entries: Vec<(String, T)>, entries: Vec<(String, T)>, is_same_entry: impl Fn(&T, &T) -> bool
+    ) -> Vec<(String, T)> {
+        let mut used_names = BTreeSet::new();
+        let previous_names: Vec<Option<String>> = entries.iter()
+            .map(|(_, entry)| {
+                let previous_name = previous_entries.iter()
+                    .find(|(previous_name, previous_entry)| {
+                        !used_names.contains(previous_name) && is_same_entry(previous_entry, entry)
+                    })
+                    .map(|(previous_name, _)| previous_name.clone())?;
+
+                used_names.insert(previous_name.clone());
+                Some(previous_name)
+            })
+            .collect();
+
+        entries.into_iter()
+            .zip(previous_names)
+            .map(|((name, entry), previous_name)| {
+                let name = previous_name.unwrap_or_else(|| Self::create_unique_name(&used_names, name));
+                used_names.insert(name.clone());
+                (name, entry)
+            })
+            .collect()
+    }
+
+    fn create_unique_name(used_names: &BTreeSet<String>, name: String) -> String {
+        if !used_names.contains(&name) {
+            return name;
+        }
+
+        (2..)
+            .map(|index| format!("{} {}", name, index))
+            .find(|unique_name| !used_names.contains(unique_name))
+            .unwrap_or(name)
+    }
+
+    fn get_program_name(command: &str) -> String {
+        let executable = command.split_whitespace().next().unwrap_or(command);
+        executable.rsplit('/').next().unwrap_or(executable).to_string()
+    }
+}
\ No newline at end of file
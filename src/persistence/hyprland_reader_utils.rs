@@ -0,0 +1,153 @@
+use gtk::gdk::RGBA;
+use crate::models::rgba_color::RGBAColor;
+use crate::persistence::hyprland_config_parser::HyprlandConfigParser;
+use crate::ui::controls::keybinds::{ALT_KEY, CONTROL_KEY, SHIFT_KEY, SUPER_KEY};
+
+pub struct HyprlandReaderUtils;
+
+impl HyprlandReaderUtils {
+    pub fn parse_bool(value: &str) -> Option<bool> {
+        match value.trim().to_lowercase().as_str() {
+            "true" | "yes" | "on" => Some(true),
+            "false" | "no" | "off" => Some(false),
+            number_value => number_value.parse::<i64>().ok().map(|number| number > 0),
+        }
+    }
+
+    pub fn parse_number<Number: std::str::FromStr>(value: &str) -> Option<Number> {
+        let first_value = value.split([',', ' ']).find(|part| !part.is_empty())?;
+        first_value.trim().parse::<Number>().ok()
+    }
+
+    pub fn parse_pair<Number: std::str::FromStr>(value: &str) -> Option<(Number, Number)> {
+        let mut values = value.split([',', ' ', 'x'])
+            .filter(|part| !part.is_empty())
+            .map(|part| part.trim().parse::<Number>());
+
+        let first_value = values.next()?.ok()?;
+        let second_value = values.next()?.ok()?;
+        Some((first_value, second_value))
+    }
+
+    pub fn parse_modifier_keys(modifier_keys: &str) -> Vec<String> {
+        modifier_keys.split([' ', '_'])
+            .filter(|modifier_key| !modifier_key.is_empty())
+            .map(|modifier_key| match modifier_key.to_uppercase().as_str() {
+                "CTRL" | "CONTROL" => CONTROL_KEY.to_string(),
+                "SHIFT" => SHIFT_KEY.to_string(),
+                "ALT" | "MOD1" => ALT_KEY.to_string(),
+                "SUPER" | "WIN" | "LOGO" | "MOD4" => SUPER_KEY.to_string(),
+                other_key => other_key.to_string(),
+            })
+            .collect()
+    }
+
+    pub fn parse_environment_variable(value: &str) -> Option<(String, String)> {
+        let (variable, variable_value) = value.split_once(',')?;
+        Some((variable.trim().to_string(), variable_value.trim().to_string()))
+    }
+
+    pub fn parse_color(value: &str) -> Option<RGBAColor> {
+        let first_color = Self::get_first_color(value.trim())?;
+
+        if let Some(hex_color) = first_color.strip_prefix("0x") {
+            let argb_color = u32::from_str_radix(hex_color, 16).ok()?;
+            let [alpha, red, green, blue] = argb_color.to_be_bytes();
+            return Some(Self::create_color(red, green, blue, alpha));
+        }
+
+        if let Some(hex_color) = Self::strip_color_function(first_color, "rgba(")
+            && let Some(color) = Self::parse_hex_color(hex_color)
+        {
+            return Some(color);
+        }
+
+        if let Some(hex_color) = Self::strip_color_function(first_color, "rgb(")
+            && let Some(color) = Self::parse_hex_color(hex_color)
+        {
+            return Some(color);
+        }
+
+        RGBA::parse(first_color).ok().map(RGBAColor::new)
+    }
+
+    pub fn apply_parsed_string(parser: &HyprlandConfigParser, key: &str, field: &mut String) {
+        if let Some(value) = parser.get_value(key) {
+            *field = value;
+        }
+    }
+
+    pub fn apply_parsed_bool(parser: &HyprlandConfigParser, key: &str, field: &mut bool) {
+        if let Some(value) = parser.get_value(key).and_then(|value| Self::parse_bool(&value)) {
+            *field = value;
+        }
+    }
+
+    pub fn apply_parsed_number<Number: std::str::FromStr>(parser: &HyprlandConfigParser, key: &str, field: &mut Number) {
+        if let Some(value) = parser.get_value(key) {
+            Self::apply_number(field, &value);
+        }
+    }
+
+    pub fn apply_number<Number: std::str::FromStr>(field: &mut Number, value: &str) {
+        if let Some(number) = Self::parse_number::<Number>(value) {
+            *field = number;
+        }
+    }
+
+    pub fn apply_parsed_color(parser: &HyprlandConfigParser, key: &str, field: &mut RGBAColor) {
+        if let Some(color) = parser.get_value(key).and_then(|value| Self::parse_color(&value)) {
+            *field = color;
+        }
+    }
+
+    pub fn apply_parsed_pair<Number: std::str::FromStr>(
+        parser: &HyprlandConfigParser, key: &str, first_field: &mut Number, second_field: &mut Number
+    ) {
+        if let Some((first_value, second_value)) = parser.get_value(key)
+            .and_then(|value| Self::parse_pair::<Number>(&value))
+        {
+            *first_field = first_value;
+            *second_field = second_value;
+        }
+    }
+
+    fn get_first_color(value: &str) -> Option<&str> {
+        if value.starts_with("rgb") {
+            let color_end_index = value.find(')')?;
+            return Some(&value[..=color_end_index]);
+        }
+
+        value.split_whitespace().next()
+    }
+
+    fn strip_color_function<'a>(value: &'a str, function_name: &str) -> Option<&'a str> {
+        value.strip_prefix(function_name)?.strip_suffix(')')
+    }
+
+    fn parse_hex_color(hex_color: &str) -> Option<RGBAColor> {
+        if !hex_color.chars().all(|character| character.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let parse_channel = |start_index: usize| {
+            u8::from_str_radix(&hex_color[start_index..start_index + 2], 16).ok()
+        };
+
+        match hex_color.len() {
+            6 => Some(Self::create_color(parse_channel(0)?, parse_channel(2)?, parse_channel(4)?, u8::MAX)),
+            8 => Some(Self::create_color(parse_channel(0)?, parse_channel(2)?, parse_channel(4)?, parse_channel(6)?)),
+            _ => None,
+        }
+    }
+
+    fn create_color(red: u8, green: u8, blue: u8, alpha: u8) -> RGBAColor {
+        let max_channel_value = u8::MAX as f32;
+        RGBAColor::new(RGBA::new(
+            red as f32 / max_channel_value,
+            green as f32 / max_channel_value,
+            blue as f32 / max_channel_value,
+            alpha as f32 / max_channel_value,
+        ))
+    }
+}
\ No newline at end of file
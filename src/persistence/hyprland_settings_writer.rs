@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use crate::models::keybinds::key_bind_configuration::KeyBindConfiguration;
 use crate::models::keybinds::system_keybind::SystemKeybind;
-use crate::persistence::settings_writer::SettingsWriter;
+use crate::models::monitor::monitor_configuration::MonitorConfiguration;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_writer::{RenderedConfig, SettingsWriter};
 use crate::models::settings::hyprland_settings::HyprlandSettings;
 use crate::models::settings::keybind_settings::KeyBindSettings;
 use crate::persistence::hyprland_writer_utils::{ConfigSectionBuilder, HyprlandWriterUtils, COMMENT_CHARACTER};
@@ -9,36 +11,99 @@ use crate::providers::module_provider::{
     FILE_MANAGER_ENTRY, NOTIFICATION_HANDLER_ENTRY, QUICK_SEARCH_ENTRY, VIRTUAL_TERMINAL_ENTRY
 };
 use crate::ui::states::input_state::CURRENT_MONITOR;
+use crate::models::keybinds::custom_keybind::DEFAULT_BIND_TYPE;
+
+pub const AUTOSTART_CONFIG_FILE: &str = "autostart.conf";
+pub const MONITORS_CONFIG_FILE: &str = "monitors.conf";
+pub const LOOK_AND_FEEL_CONFIG_FILE: &str = "looknfeel.conf";
+pub const INPUT_CONFIG_FILE: &str = "input.conf";
+pub const KEYBINDS_CONFIG_FILE: &str = "keybinds.conf";
+const DISABLE_MONITOR_VALUE: &str = "disable";
 
 pub struct HyprlandSettingsWriter {
-    program_variables: HashMap<String, (String, String)>,
-    config_lines: Vec<String>
+    program_variables: BTreeMap<String, (String, String)>,
+    config_lines: Vec<String>,
+    include_configs: Vec<(String, Vec<String>)>,
+    split_config_files: bool,
 }
 
 impl SettingsWriter<HyprlandSettings> for HyprlandSettingsWriter {
-    fn serialize_settings(&mut self, settings: HyprlandSettings) {
+    fn serialize_settings(&mut self, settings: HyprlandSettings) -> Result<(), SettingsError> {
         self.index_program_variables(&settings);
+        self.split_config_files = settings.config_file_settings.split_config_files;
 
         self.serialize_program_settings();
-        self.serialize_startup_settings(&settings);
-        self.serialize_monitor_settings(&settings);
-        self.serialize_appearance_settings(&settings);
-        self.serialize_input_settings(&settings);
-        self.serialize_keybinds_settings(&settings);
+        if self.split_config_files {
+            self.add_comment_section("INCLUDED CONFIG FILES".to_string());
+        }
+
+        self.serialize_include_config(AUTOSTART_CONFIG_FILE, |writer| writer.serialize_startup_settings(&settings));
+        self.serialize_include_config(MONITORS_CONFIG_FILE, |writer| writer.serialize_monitor_settings(&settings));
+        self.serialize_include_config(LOOK_AND_FEEL_CONFIG_FILE, |writer| writer.serialize_appearance_settings(&settings));
+        self.serialize_include_config(INPUT_CONFIG_FILE, |writer| writer.serialize_input_settings(&settings));
+        self.serialize_include_config(KEYBINDS_CONFIG_FILE, |writer| writer.serialize_keybinds_settings(&settings));
+        Ok(())
     }
 
-    fn write_to_config(&self) {
+    fn render_configs(&self) -> Result<Vec<RenderedConfig>, SettingsError> {
         let hyprland_config_file_path = HyprlandWriterUtils::create_hyprland_config_path("hyprland.conf");
-        HyprlandWriterUtils::write_content_to_file(hyprland_config_file_path.as_str(), self.config_lines.clone())
+        let mut rendered_configs: Vec<RenderedConfig> = HyprlandWriterUtils::render_config(
+            hyprland_config_file_path, self.config_lines.clone()
+        )?.into_iter().collect();
+
+        for (config_name, config_lines) in &self.include_configs {
+            let include_config_file_path = HyprlandWriterUtils::create_hyprland_config_path(config_name);
+            rendered_configs.extend(HyprlandWriterUtils::render_config(include_config_file_path, config_lines.clone())?);
+        }
+
+        Ok(rendered_configs)
     }
 }
 
 impl HyprlandSettingsWriter {
     pub fn new() -> Self {
         Self {
-            program_variables: HashMap::new(),
+            program_variables: BTreeMap::new(),
             config_lines: Vec::new(),
+            include_configs: Vec::new(),
+            split_config_files: false,
+        }
+    }
+
+    pub fn render_live_config(settings: &HyprlandSettings) -> String {
+        let mut hyprland_settings_writer = Self::new();
+        hyprland_settings_writer.serialize_monitor_settings(settings);
+        hyprland_settings_writer.serialize_appearance_settings(settings);
+        hyprland_settings_writer.serialize_input_settings(settings);
+        hyprland_settings_writer.config_lines.join("\n")
+    }
+
+    pub fn render_single_config(settings: &HyprlandSettings) -> String {
+        let mut hyprland_settings_writer = Self::new();
+        hyprland_settings_writer.index_program_variables(settings);
+        hyprland_settings_writer.serialize_program_settings();
+        hyprland_settings_writer.serialize_startup_settings(settings);
+        hyprland_settings_writer.serialize_monitor_settings(settings);
+        hyprland_settings_writer.serialize_appearance_settings(settings);
+        hyprland_settings_writer.serialize_input_settings(settings);
+        hyprland_settings_writer.serialize_keybinds_settings(settings);
+        hyprland_settings_writer.config_lines.join("\n")
+    }
+
+    fn serialize_include_config(&mut self, config_name: &str, serialize_settings: impl FnOnce(&mut Self)) {
+        if !self.split_config_files {
+            serialize_settings(self);
+            return;
         }
+
+        let main_config_lines = std::mem::take(&mut self.config_lines);
+        serialize_settings(self);
+
+        let include_config_lines = std::mem::replace(&mut self.config_lines, main_config_lines);
+        self.include_configs.push((config_name.to_string(), include_config_lines));
+        self.add_line_entry(HyprlandWriterUtils::create_value_pair(
+            "source".to_string(), format!("./{}", config_name)
+        ));
     }
 
     fn index_program_variables(&mut self, settings: &HyprlandSettings) {
@@ -88,27 +153,32 @@ impl HyprlandSettingsWriter {
 
         let monitor_settings = settings.monitor_settings.monitor_configurations.clone();
         for (monitor_port, monitor_configuration) in monitor_settings {
-            if !monitor_configuration.enabled {
-                continue;
-            }
-
-            let video_mode = monitor_configuration.video_mode;
-            let transformation_settings = monitor_configuration.orientation.get_hyprland_orientation_code();
-            let monitor_entry = format!(
-                "monitor = {}, {}x{}@{}, {}x{}, {}, transform, {}",
-                monitor_port,
-                video_mode.width_resolution,
-                video_mode.height_resolution,
-                video_mode.refresh_rate,
-                monitor_configuration.offset.get_x(),
-                monitor_configuration.offset.get_y(),
-                monitor_configuration.resolution_scale,
-                transformation_settings
-            );
-            self.add_line_entry(monitor_entry);
+            let monitor_value = if monitor_configuration.enabled {
+                Self::create_monitor_value(&monitor_port, &monitor_configuration)
+            } else {
+                Self::create_disabled_monitor_value(&monitor_port)
+            };
+            self.add_line_entry(format!("monitor = {}", monitor_value));
         }
     }
 
+    pub fn create_disabled_monitor_value(monitor_port: &str) -> String {
+        format!("{}, {}", monitor_port, DISABLE_MONITOR_VALUE)
+    }
+
+    pub fn create_monitor_value(monitor_port: &str, monitor_configuration: &MonitorConfiguration) -> String {
+        let transformation_settings = monitor_configuration.orientation.get_hyprland_orientation_code();
+        format!(
+            "{}, {}, {}x{}, {}, transform, {}",
+            monitor_port,
+            monitor_configuration.get_hyprland_video_mode(),
+            monitor_configuration.offset.get_x(),
+            monitor_configuration.offset.get_y(),
+            monitor_configuration.resolution_scale,
+            transformation_settings
+        )
+    }
+
     fn serialize_appearance_settings(&mut self, settings: &HyprlandSettings) {
         let cursor_section_lines = Self::create_cursor_config_section(settings);
         let general_section_lines = Self::create_general_config_section(settings);
@@ -331,6 +401,9 @@ impl HyprlandSettingsWriter {
         self.create_action_keybind_section(
             keybinds_settings, SystemKeybind::MoveWorkspaceZero, vec!["movetoworkspace", "0"]
         );
+        self.add_new_line();
+
+        self.create_custom_keybind_section(keybinds_settings);
     }
 
     fn create_program_keybind_section(&mut self, settings: &KeyBindSettings, program_name: &str, system_keybind: SystemKeybind) {
@@ -350,7 +423,23 @@ impl HyprlandSettingsWriter {
         }
     }
 
+    fn create_custom_keybind_section(&mut self, settings: &KeyBindSettings) {
+        for (_, custom_keybind) in settings.custom_keybinds.clone() {
+            let arguments = vec!["exec", custom_keybind.command.as_str()];
+            let keybind_entry = Self::create_typed_keybind_entry(
+                &custom_keybind.bind_type, custom_keybind.keybind.clone(), arguments
+            );
+            self.add_line_entry(keybind_entry);
+        }
+    }
+
     fn create_keybind_entry(keybind_configuration: KeyBindConfiguration, arguments: Vec<&str>) -> String {
+        Self::create_typed_keybind_entry(DEFAULT_BIND_TYPE, keybind_configuration, arguments)
+    }
+
+    fn create_typed_keybind_entry(
+        bind_type: &str, keybind_configuration: KeyBindConfiguration, arguments: Vec<&str>
+    ) -> String {
         let key_modifiers = keybind_configuration.get_modifier_keys().join(" ");
         let mut key_arguments = key_modifiers;
         if let Some(key) = keybind_configuration.get_key() {
@@ -358,7 +447,7 @@ impl HyprlandSettingsWriter {
         }
         
         let command_arguments = arguments.join(", ");
-        format!("bind = {}, {}", key_arguments, command_arguments)
+        format!("{} = {}, {}", bind_type, key_arguments, command_arguments)
     }
 
     fn create_cursor_config_section(settings: &HyprlandSettings) -> Vec<String> {
@@ -372,8 +461,8 @@ impl HyprlandSettingsWriter {
     }
 
     fn create_general_config_section(settings: &HyprlandSettings) -> Vec<String> {
-        let gaps_in_value = settings.appearance_settings.inner_gab.to_string();
-        let gaps_out_value = settings.appearance_settings.outer_gab.to_string();
+        let gaps_in_value = settings.appearance_settings.inner_gap.to_string();
+        let gaps_out_value = settings.appearance_settings.outer_gap.to_string();
         let border_size_value = settings.appearance_settings.border_size.to_string();
         let column_active_border_value = settings.appearance_settings.active_border_color.to_string();
         let column_inactive_border_value = settings.appearance_settings.inactive_border_color.to_string();
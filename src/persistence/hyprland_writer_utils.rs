@@ -1,12 +1,15 @@
-use std::env;
-use std::fs::OpenOptions;
-use std::io::{BufWriter, Write};
+use std::fs;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::io::Write;
 use std::path::Path;
+use crate::persistence::config_directory::ConfigDirectory;
+use crate::persistence::hyprland_config_merger::HyprlandConfigMerger;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_writer::RenderedConfig;
 
 pub const COMMENT_CHARACTER: char = '#';
 
-pub const HYPRLAND_CONFIG_PATH: &str = ".config/hypr";
-
 pub struct ConfigSectionBuilder {
     section_title: String,
     section_lines: Vec<String>
@@ -68,28 +71,60 @@ impl HyprlandWriterUtils {
         comment
     }
     
-    pub fn write_content_to_file(file_path: &str, config_lines: Vec<String>) {
-        if config_lines.len() == 0 {
-            return;
+    pub fn render_content(file_path: &str, config_lines: Vec<String>) -> Result<Option<String>, SettingsError> {
+        if config_lines.is_empty() {
+            return Ok(None);
         }
 
-        let file = OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(file_path)
-            .expect("Cannot open hyprland config file");
-        let mut buffer_writer = BufWriter::new(file);
+        let config_lines = match fs::read_to_string(file_path) {
+            Ok(existing_content) => {
+                let config_directory = Path::new(file_path).parent()
+                    .map(|directory| directory.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                HyprlandConfigMerger::new().merge(&existing_content, config_lines, &config_directory)
+            },
+            Err(error) if error.kind() == ErrorKind::NotFound => HyprlandConfigMerger::create_managed_block(config_lines),
+            Err(error) => return Err(SettingsError::io(file_path, error)),
+        };
+
+        Ok(Some(config_lines.join("\n")))
+    }
+
+    pub fn render_config(config_path: String, config_lines: Vec<String>) -> Result<Option<RenderedConfig>, SettingsError> {
+        let rendered_config = Self::render_content(config_path.as_str(), config_lines)?
+            .map(|content| RenderedConfig {
+                config_path,
+                content,
+            });
+
+        Ok(rendered_config)
+    }
+
+    pub fn write_content_atomically(file_path: &str, content: &[u8]) -> Result<(), SettingsError> {
+        let file_path = fs::canonicalize(file_path).unwrap_or(Path::new(file_path).to_path_buf());
+        let file_name = file_path.file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let temporary_file_path = file_path.with_file_name(format!(".{}.tmp", file_name));
+
+        let write_result = fs::create_dir_all(file_path.parent().unwrap_or(Path::new("")))
+            .and_then(|_| File::create(&temporary_file_path))
+            .and_then(|mut temporary_file| {
+                temporary_file.write_all(content)?;
+                temporary_file.sync_all()
+            })
+            .and_then(|_| fs::rename(&temporary_file_path, &file_path));
+
+        if let Err(error) = write_result {
+            let _ = fs::remove_file(&temporary_file_path);
+            return Err(SettingsError::io(&file_path.to_string_lossy(), error));
+        }
 
-        let file_content = config_lines.join("\n");
-        buffer_writer.write_all(file_content.as_bytes()).expect("Cannot write to config file");
+        Ok(())
     }
 
     pub fn create_hyprland_config_path(config_name: &str) -> String {
-        let home_path = env::var("HOME").unwrap_or("".to_string());
-        let config_file_path = Path::new(&home_path);
-        let config_file_path = config_file_path.join(HYPRLAND_CONFIG_PATH);
-        let config_file_path = config_file_path.join(config_name);
-        config_file_path.to_str().unwrap().to_string()
+        let config_file_path = ConfigDirectory::get_path().join(config_name);
+        config_file_path.to_string_lossy().to_string()
     }
 }
\ No newline at end of file
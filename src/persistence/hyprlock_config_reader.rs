@@ -0,0 +1,114 @@
+use std::fs;
+use crate::models::settings::lockscreen_settings::LockScreenSettings;
+use crate::persistence::hyprland_config_parser::HyprlandConfigParser;
+use crate::persistence::hyprland_reader_utils::HyprlandReaderUtils;
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_reader::SettingsReader;
+
+pub struct HyprlockConfigReader {
+    parser: HyprlandConfigParser,
+    deserialized_settings: LockScreenSettings,
+}
+
+impl SettingsReader<LockScreenSettings> for HyprlockConfigReader {
+    fn read_from_config(&mut self) -> Result<(), SettingsError> {
+        let hyprlock_config_file = HyprlandWriterUtils::create_hyprland_config_path("hyprlock.conf");
+        self.parser.parse_file(hyprlock_config_file.as_str())
+    }
+
+    fn deserialize_settings(&mut self) -> LockScreenSettings {
+        self.deserialize_general_section();
+        self.deserialize_background_section();
+        self.deserialize_input_field_section();
+        self.deserialize_label_section();
+        self.deserialized_settings.clone()
+    }
+}
+
+impl HyprlockConfigReader {
+    pub fn new(base_settings: LockScreenSettings) -> Self {
+        Self {
+            parser: HyprlandConfigParser::new(),
+            deserialized_settings: base_settings,
+        }
+    }
+
+    pub fn config_file_exists() -> bool {
+        let hyprlock_config_file = HyprlandWriterUtils::create_hyprland_config_path("hyprlock.conf");
+        fs::exists(hyprlock_config_file).unwrap_or(false)
+    }
+
+    fn deserialize_general_section(&mut self) {
+        let parser = &self.parser;
+        let settings = &mut self.deserialized_settings;
+
+        HyprlandReaderUtils::apply_parsed_bool(parser, "general:hide_cursor", &mut settings.hide_cursor);
+        HyprlandReaderUtils::apply_parsed_number(parser, "general:grace", &mut settings.grace);
+        HyprlandReaderUtils::apply_parsed_number(parser, "input-field:fail_timeout", &mut settings.fall_timeout);
+        HyprlandReaderUtils::apply_parsed_number(parser, "general:fall_timeout", &mut settings.fall_timeout);
+    }
+
+    fn deserialize_background_section(&mut self) {
+        let parser = &self.parser;
+        let settings = &mut self.deserialized_settings;
+
+        HyprlandReaderUtils::apply_parsed_string(parser, "background:path", &mut settings.lockscreen_wallpaper);
+        HyprlandReaderUtils::apply_parsed_number(parser, "background:blur_size", &mut settings.blur_size);
+        HyprlandReaderUtils::apply_parsed_number(parser, "background:blur_passes", &mut settings.blur_passes);
+        HyprlandReaderUtils::apply_parsed_number(parser, "background:noise", &mut settings.noise);
+        HyprlandReaderUtils::apply_parsed_number(parser, "background:contrast", &mut settings.contrast);
+        HyprlandReaderUtils::apply_parsed_number(parser, "background:brightness", &mut settings.brightness);
+        HyprlandReaderUtils::apply_parsed_number(parser, "background:vibrancy", &mut settings.vibrancy);
+    }
+
+    fn deserialize_input_field_section(&mut self) {
+        let parser = &self.parser;
+        let settings = &mut self.deserialized_settings;
+
+        HyprlandReaderUtils::apply_parsed_pair(
+            parser, "input-field:size", &mut settings.input_width, &mut settings.input_height
+        );
+        HyprlandReaderUtils::apply_parsed_number(
+            parser, "input-field:outline_thickness", &mut settings.input_outline_thickness
+        );
+        HyprlandReaderUtils::apply_parsed_number(parser, "input-field:dots_size", &mut settings.input_dots_size);
+        HyprlandReaderUtils::apply_parsed_number(parser, "input-field:dots_spacing", &mut settings.input_dots_spacing);
+        HyprlandReaderUtils::apply_parsed_bool(parser, "input-field:dots_center", &mut settings.input_dots_center);
+        HyprlandReaderUtils::apply_parsed_color(parser, "input-field:outer_color", &mut settings.input_outer_color);
+        HyprlandReaderUtils::apply_parsed_color(parser, "input-field:inner_color", &mut settings.input_inner_color);
+        HyprlandReaderUtils::apply_parsed_color(parser, "input-field:font_color", &mut settings.input_font_color);
+        HyprlandReaderUtils::apply_parsed_string(
+            parser, "input-field:placeholder_text", &mut settings.input_placeholder_text
+        );
+        HyprlandReaderUtils::apply_parsed_bool(parser, "input-field:hide_input", &mut settings.hide_input);
+        HyprlandReaderUtils::apply_parsed_pair(
+            parser, "input-field:position", &mut settings.input_x_position, &mut settings.input_y_position
+        );
+        HyprlandReaderUtils::apply_parsed_string(
+            parser, "input-field:halign", &mut settings.input_horizontal_alignment
+        );
+        HyprlandReaderUtils::apply_parsed_string(
+            parser, "input-field:valign", &mut settings.input_vertical_alignment
+        );
+    }
+
+    fn deserialize_label_section(&mut self) {
+        let parser = &self.parser;
+        let settings = &mut self.deserialized_settings;
+
+        HyprlandReaderUtils::apply_parsed_string(parser, "label:text", &mut settings.display_text);
+        HyprlandReaderUtils::apply_parsed_color(parser, "label:color", &mut settings.display_text_color);
+        HyprlandReaderUtils::apply_parsed_number(parser, "label:font_size", &mut settings.display_text_font_size);
+        HyprlandReaderUtils::apply_parsed_string(parser, "label:font_family", &mut settings.display_text_font);
+        HyprlandReaderUtils::apply_parsed_pair(
+            parser, "label:position", &mut settings.display_text_x_position, &mut settings.display_text_y_position
+        );
+        HyprlandReaderUtils::apply_parsed_string(
+            parser, "label:halign", &mut settings.display_text_horizontal_alignment
+        );
+        HyprlandReaderUtils::apply_parsed_string(
+            parser, "label:valign", &mut settings.display_text_vertical_alignment
+        );
+    }
+}
\ No newline at end of file
@@ -1,13 +1,14 @@
 use crate::models::settings::lockscreen_settings::LockScreenSettings;
 use crate::persistence::hyprland_writer_utils::{ConfigSectionBuilder, HyprlandWriterUtils};
-use crate::persistence::settings_writer::SettingsWriter;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_writer::{RenderedConfig, SettingsWriter};
 
 pub struct HyprlockSettingsWriter {
     config_lines: Vec<String>
 }
 
 impl SettingsWriter<LockScreenSettings> for HyprlockSettingsWriter {
-    fn serialize_settings(&mut self, settings: LockScreenSettings) {
+    fn serialize_settings(&mut self, settings: LockScreenSettings) -> Result<(), SettingsError> {
         let general_config_section_lines = self.create_general_config_section(&settings);
         self.add_line_entries(general_config_section_lines);
 
@@ -19,11 +20,14 @@ impl SettingsWriter<LockScreenSettings> for HyprlockSettingsWriter {
 
         let text_display_config_section_lines = self.create_text_display_config_section(&settings);
         self.add_line_entries(text_display_config_section_lines);
+
+        Ok(())
     }
 
-    fn write_to_config(&self) {
+    fn render_configs(&self) -> Result<Vec<RenderedConfig>, SettingsError> {
         let hyprlock_config_file_path = HyprlandWriterUtils::create_hyprland_config_path("hyprlock.conf");
-        HyprlandWriterUtils::write_content_to_file(hyprlock_config_file_path.as_str(), self.config_lines.clone());
+        let rendered_config = HyprlandWriterUtils::render_config(hyprlock_config_file_path, self.config_lines.clone())?;
+        Ok(rendered_config.into_iter().collect())
     }
 }
 
@@ -34,6 +38,10 @@ impl HyprlockSettingsWriter {
         }
     }
 
+    pub fn get_config_content(&self) -> String {
+        self.config_lines.join("\n")
+    }
+
     fn create_general_config_section(&self, settings: &LockScreenSettings) -> Vec<String> {
         ConfigSectionBuilder::new("general".to_string())
             .add_line(HyprlandWriterUtils::create_value_pair(
@@ -83,7 +91,7 @@ impl HyprlockSettingsWriter {
                 )
             ))
             .add_line(HyprlandWriterUtils::create_value_pair(
-                "outline_thickness".to_string(), settings.grace.to_string()
+                "outline_thickness".to_string(), settings.input_outline_thickness.to_string()
             ))
             .add_line(HyprlandWriterUtils::create_value_pair(
                 "dots_size".to_string(), settings.input_dots_size.to_string()
@@ -0,0 +1,83 @@
+use std::fs;
+use crate::models::settings::appearance_settings::AppearanceSettings;
+use crate::persistence::hyprland_config_parser::HyprlandConfigParser;
+use crate::persistence::hyprland_reader_utils::HyprlandReaderUtils;
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_reader::SettingsReader;
+
+pub struct HyprpaperConfigReader {
+    parser: HyprlandConfigParser,
+    deserialized_settings: AppearanceSettings,
+}
+
+impl SettingsReader<AppearanceSettings> for HyprpaperConfigReader {
+    fn read_from_config(&mut self) -> Result<(), SettingsError> {
+        let hyprpaper_config_file = HyprlandWriterUtils::create_hyprland_config_path("hyprpaper.conf");
+        self.parser.parse_file(hyprpaper_config_file.as_str())
+    }
+
+    fn deserialize_settings(&mut self) -> AppearanceSettings {
+        self.deserialize_wallpaper_settings();
+        self.deserialize_hyprpaper_options();
+        self.deserialized_settings.clone()
+    }
+}
+
+impl HyprpaperConfigReader {
+    pub fn new(base_settings: AppearanceSettings) -> Self {
+        Self {
+            parser: HyprlandConfigParser::new(),
+            deserialized_settings: base_settings,
+        }
+    }
+
+    pub fn config_file_exists() -> bool {
+        let hyprpaper_config_file = HyprlandWriterUtils::create_hyprland_config_path("hyprpaper.conf");
+        fs::exists(hyprpaper_config_file).unwrap_or(false)
+    }
+
+    fn deserialize_wallpaper_settings(&mut self) {
+        let preloaded_paths = self.parser.get_values("preload");
+        let wallpaper_entries = self.parser.get_values("wallpaper");
+        if preloaded_paths.is_empty() && wallpaper_entries.is_empty() {
+            return;
+        }
+
+        let settings = &mut self.deserialized_settings;
+        settings.wallpaper_path = String::new();
+        settings.monitor_wallpapers.clear();
+
+        for wallpaper_entry in wallpaper_entries {
+            let Some((monitor_port, wallpaper_path)) = wallpaper_entry.split_once(',') else {
+                continue;
+            };
+
+            let monitor_port = monitor_port.trim();
+            let wallpaper_path = wallpaper_path.trim().to_string();
+            if monitor_port.is_empty() {
+                settings.wallpaper_path = wallpaper_path;
+            } else {
+                settings.monitor_wallpapers.insert(monitor_port.to_string(), wallpaper_path);
+            }
+        }
+
+        if settings.wallpaper_path.is_empty()
+            && settings.monitor_wallpapers.is_empty()
+            && let Some(preloaded_path) = preloaded_paths.first()
+        {
+            settings.wallpaper_path = preloaded_path.clone();
+        }
+    }
+
+    fn deserialize_hyprpaper_options(&mut self) {
+        let parser = &self.parser;
+        let settings = &mut self.deserialized_settings;
+
+        HyprlandReaderUtils::apply_parsed_bool(parser, "splash", &mut settings.wallpaper_splash);
+
+        let mut ipc_enabled = !settings.disable_wallpaper_ipc;
+        HyprlandReaderUtils::apply_parsed_bool(parser, "ipc", &mut ipc_enabled);
+        settings.disable_wallpaper_ipc = !ipc_enabled;
+    }
+}
\ No newline at end of file
@@ -1,22 +1,22 @@
 use crate::models::settings::appearance_settings::AppearanceSettings;
 use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
-use crate::persistence::settings_writer::SettingsWriter;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_writer::{RenderedConfig, SettingsWriter};
 
 pub struct HyprpaperSettingsWriter {
     config_lines: Vec<String>
 }
 
 impl SettingsWriter<AppearanceSettings> for HyprpaperSettingsWriter {
-    fn serialize_settings(&mut self, settings: AppearanceSettings) {
+    fn serialize_settings(&mut self, settings: AppearanceSettings) -> Result<(), SettingsError> {
         self.serialize_wallpaper_settings(&settings);
+        Ok(())
     }
 
-    fn write_to_config(&self) {
+    fn render_configs(&self) -> Result<Vec<RenderedConfig>, SettingsError> {
         let hyprpaper_config_file_path = HyprlandWriterUtils::create_hyprland_config_path("hyprpaper.conf");
-
-        if !self.config_lines.is_empty() {
-            HyprlandWriterUtils::write_content_to_file(hyprpaper_config_file_path.as_str(), self.config_lines.clone())
-        }
+        let rendered_config = HyprlandWriterUtils::render_config(hyprpaper_config_file_path, self.config_lines.clone())?;
+        Ok(rendered_config.into_iter().collect())
     }
 }
 
@@ -27,18 +27,58 @@ impl HyprpaperSettingsWriter {
         }
     }
 
+    pub fn get_config_content(&self) -> String {
+        self.config_lines.join("\n")
+    }
+
     fn serialize_wallpaper_settings(&mut self, settings: &AppearanceSettings) {
         let wallpaper_path = settings.wallpaper_path.clone();
-        if wallpaper_path.is_empty() {
+        let monitor_wallpapers = &settings.monitor_wallpapers;
+
+        let mut preloaded_paths: Vec<String> = Vec::new();
+        if !wallpaper_path.is_empty() {
+            preloaded_paths.push(wallpaper_path.clone());
+        }
+
+        for monitor_wallpaper_path in monitor_wallpapers.values() {
+            if !preloaded_paths.contains(monitor_wallpaper_path) {
+                preloaded_paths.push(monitor_wallpaper_path.to_string());
+            }
+        }
+
+        if preloaded_paths.is_empty() {
             return
         }
 
-        self.add_line_entry(HyprlandWriterUtils::create_value_pair(
-            "preload".to_string(), wallpaper_path.clone()
-        ));
-        self.add_line_entry(HyprlandWriterUtils::create_value_pair(
-            "wallpaper".to_string(), format!(",{}", wallpaper_path)
-        ));
+        for preloaded_path in preloaded_paths {
+            self.add_line_entry(HyprlandWriterUtils::create_value_pair(
+                "preload".to_string(), preloaded_path
+            ));
+        }
+
+        if !wallpaper_path.is_empty() {
+            self.add_line_entry(HyprlandWriterUtils::create_value_pair(
+                "wallpaper".to_string(), format!(",{}", wallpaper_path)
+            ));
+        }
+
+        for (monitor_port, monitor_wallpaper_path) in monitor_wallpapers {
+            self.add_line_entry(HyprlandWriterUtils::create_value_pair(
+                "wallpaper".to_string(), format!("{},{}", monitor_port, monitor_wallpaper_path)
+            ));
+        }
+
+        if settings.wallpaper_splash {
+            self.add_line_entry(HyprlandWriterUtils::create_value_pair(
+                "splash".to_string(), settings.wallpaper_splash.to_string()
+            ));
+        }
+
+        if settings.disable_wallpaper_ipc {
+            self.add_line_entry(HyprlandWriterUtils::create_value_pair(
+                "ipc".to_string(), "off".to_string()
+            ));
+        }
     }
 
     fn add_line_entry(&mut self, text: String) {
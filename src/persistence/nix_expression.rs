@@ -0,0 +1,154 @@
+// Converts rendered hyprlang config content into the Nix attribute sets the home-manager modules expect.
+// Sections become nested attribute sets and keys that may appear several times become lists, e.g.
+// "decoration { blur { size = 8 } }" turns into "decoration = { blur = { size = 8; }; };".
+
+use std::collections::BTreeMap;
+use crate::persistence::hyprland_config_parser::HyprlandConfigParser;
+
+const INDENTATION: &str = "  ";
+const LIST_KEYS: [&str; 6] = ["bind", "env", "exec-once", "monitor", "preload", "wallpaper"];
+
+#[derive(Debug, Clone)]
+pub enum NixExpression {
+    Literal(String),
+    List(Vec<NixExpression>),
+    AttributeSet(BTreeMap<String, NixExpression>),
+}
+
+impl NixExpression {
+    pub fn from_config_content(config_content: &str) -> Self {
+        let mut sections: Vec<(String, BTreeMap<String, NixExpression>)> = vec![("".to_string(), BTreeMap::new())];
+
+        for line in config_content.lines() {
+            let line = HyprlandConfigParser::strip_comment(line);
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line == "}" {
+                if sections.len() > 1
+                    && let Some((section_name, section_attributes)) = sections.pop()
+                    && let Some((_, parent_attributes)) = sections.last_mut()
+                {
+                    Self::insert_attribute(parent_attributes, section_name, NixExpression::AttributeSet(section_attributes));
+                }
+                continue;
+            }
+
+            if let Some(section_name) = line.strip_suffix('{') {
+                sections.push((section_name.trim().to_string(), BTreeMap::new()));
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+
+            if let Some((_, attributes)) = sections.last_mut() {
+                let value = NixExpression::Literal(Self::create_literal(value.trim()));
+                Self::insert_attribute(attributes, key.trim().to_string(), value);
+            }
+        }
+
+        // Unclosed sections are folded into their parents
+        while sections.len() > 1 {
+            if let Some((section_name, section_attributes)) = sections.pop()
+                && let Some((_, parent_attributes)) = sections.last_mut()
+            {
+                Self::insert_attribute(parent_attributes, section_name, NixExpression::AttributeSet(section_attributes));
+            }
+        }
+
+        let root_attributes = sections.pop()
+            .map(|(_, attributes)| attributes)
+            .unwrap_or_default();
+        NixExpression::AttributeSet(root_attributes)
+    }
+
+    pub fn render(&self, indentation_level: usize) -> String {
+        let indentation = INDENTATION.repeat(indentation_level);
+        let inner_indentation = INDENTATION.repeat(indentation_level + 1);
+
+        match self {
+            NixExpression::Literal(literal) => literal.clone(),
+            NixExpression::List(items) if items.is_empty() => "[ ]".to_string(),
+            NixExpression::List(items) => {
+                let mut rendered_list = "[\n".to_string();
+                for item in items {
+                    rendered_list.push_str(&format!("{}{}\n", inner_indentation, item.render(indentation_level + 1)));
+                }
+                rendered_list.push_str(&format!("{}]", indentation));
+                rendered_list
+            },
+            NixExpression::AttributeSet(attributes) if attributes.is_empty() => "{ }".to_string(),
+            NixExpression::AttributeSet(attributes) => {
+                let mut rendered_attribute_set = "{\n".to_string();
+                for (name, value) in attributes {
+                    rendered_attribute_set.push_str(&format!(
+                        "{}{} = {};\n", inner_indentation, Self::create_attribute_name(name), value.render(indentation_level + 1)
+                    ));
+                }
+                rendered_attribute_set.push_str(&format!("{}}}", indentation));
+                rendered_attribute_set
+            }
+        }
+    }
+
+    fn insert_attribute(attributes: &mut BTreeMap<String, NixExpression>, name: String, value: NixExpression) {
+        match attributes.remove(&name) {
+            Some(NixExpression::List(mut items)) => {
+                items.push(value);
+                attributes.insert(name, NixExpression::List(items));
+            },
+            Some(existing_value) => {
+                attributes.insert(name, NixExpression::List(vec![existing_value, value]));
+            },
+            None if LIST_KEYS.contains(&name.as_str()) => {
+                attributes.insert(name, NixExpression::List(vec![value]));
+            },
+            None => {
+                attributes.insert(name, value);
+            }
+        }
+    }
+
+    fn create_literal(value: &str) -> String {
+        if value == "true" || value == "false" {
+            return value.to_string();
+        }
+
+        let is_number = value.chars().all(|character| character.is_ascii_digit() || character == '.' || character == '-')
+            && value.parse::<f64>().is_ok()
+            && !value.starts_with('.')
+            && !value.ends_with('.');
+
+        if is_number {
+            // Negative numbers need parentheses inside of lists
+            return if value.starts_with('-') { format!("({})", value) } else { value.to_string() };
+        }
+
+        Self::create_string(value)
+    }
+
+    fn create_attribute_name(name: &str) -> String {
+        let mut characters = name.chars();
+        let is_identifier = characters.next()
+            .is_some_and(|character| character.is_ascii_alphabetic() || character == '_')
+            && characters.all(|character| character.is_ascii_alphanumeric() || "_-'".contains(character));
+
+        if is_identifier {
+            name.to_string()
+        } else {
+            Self::create_string(name)
+        }
+    }
+
+    fn create_string(value: &str) -> String {
+        let escaped_value = value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace("${", "\\${");
+        format!("\"{}\"", escaped_value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use crate::models::settings::hyprland_settings::HyprlandSettings;
+use crate::persistence::hyprland_settings_writer::HyprlandSettingsWriter;
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+use crate::persistence::hyprlock_settings_writer::HyprlockSettingsWriter;
+use crate::persistence::hyprpaper_settings_writer::HyprpaperSettingsWriter;
+use crate::persistence::nix_expression::NixExpression;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_writer::{RenderedConfig, SettingsWriter};
+
+pub const NIX_CONFIG_FILE: &str = "hyprsettings.nix";
+
+const HYPRLAND_ATTRIBUTE_PATH: &str = "wayland.windowManager.hyprland.settings";
+const HYPRLOCK_ATTRIBUTE_PATH: &str = "programs.hyprlock.settings";
+const HYPRPAPER_ATTRIBUTE_PATH: &str = "services.hyprpaper.settings";
+
+pub struct NixSettingsWriter {
+    serialized_settings: String
+}
+
+impl SettingsWriter<HyprlandSettings> for NixSettingsWriter {
+    fn serialize_settings(&mut self, settings: HyprlandSettings) -> Result<(), SettingsError> {
+        let mut hyprlock_settings_writer = HyprlockSettingsWriter::new();
+        hyprlock_settings_writer.serialize_settings(settings.lockscreen_settings.clone())?;
+
+        let mut hyprpaper_settings_writer = HyprpaperSettingsWriter::new();
+        hyprpaper_settings_writer.serialize_settings(settings.appearance_settings.clone())?;
+
+        let module_attributes = [
+            (HYPRLAND_ATTRIBUTE_PATH, HyprlandSettingsWriter::render_single_config(&settings)),
+            (HYPRLOCK_ATTRIBUTE_PATH, hyprlock_settings_writer.get_config_content()),
+            (HYPRPAPER_ATTRIBUTE_PATH, hyprpaper_settings_writer.get_config_content()),
+        ];
+
+        let mut module_lines = vec![
+            HyprlandWriterUtils::create_comment("Generated by HyprSettings, import this module in your home-manager configuration"),
+            "{".to_string(),
+        ];
+
+        for (attribute_path, config_content) in module_attributes {
+            let nix_expression = NixExpression::from_config_content(&config_content);
+            module_lines.push(format!("  {} = {};", attribute_path, nix_expression.render(1)));
+        }
+
+        module_lines.push("}".to_string());
+        self.serialized_settings = module_lines.join("\n");
+        Ok(())
+    }
+
+    fn render_configs(&self) -> Result<Vec<RenderedConfig>, SettingsError> {
+        if self.serialized_settings.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![RenderedConfig {
+            config_path: HyprlandWriterUtils::create_hyprland_config_path(NIX_CONFIG_FILE),
+            content: self.serialized_settings.clone(),
+        }])
+    }
+}
+
+impl NixSettingsWriter {
+    pub fn new() -> Self {
+        Self {
+            serialized_settings: "".to_string(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiagnosticSeverity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingsDiagnostic {
+    pub field_path: String,
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+}
+
+impl SettingsDiagnostic {
+    pub fn warning(field_path: &str, message: impl Display) -> Self {
+        SettingsDiagnostic {
+            field_path: field_path.to_string(),
+            severity: DiagnosticSeverity::Warning,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn error(field_path: &str, message: impl Display) -> Self {
+        SettingsDiagnostic {
+            field_path: field_path.to_string(),
+            severity: DiagnosticSeverity::Error,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == DiagnosticSeverity::Error
+    }
+}
+
+impl Display for SettingsDiagnostic {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.severity {
+            DiagnosticSeverity::Warning => write!(formatter, "Warning in {}: {}", self.field_path, self.message),
+            DiagnosticSeverity::Error => write!(formatter, "Error in {}: {}", self.field_path, self.message),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone)]
+pub enum SettingsError {
+    Io { path: String, message: String },
+    Parse { path: String, message: String },
+    Validation { message: String },
+    Ipc { request: String, message: String },
+}
+
+impl SettingsError {
+    pub fn io(path: &str, error: std::io::Error) -> Self {
+        SettingsError::Io {
+            path: path.to_string(),
+            message: error.to_string(),
+        }
+    }
+
+    pub fn parse(path: &str, error: impl Display) -> Self {
+        SettingsError::Parse {
+            path: path.to_string(),
+            message: error.to_string(),
+        }
+    }
+
+    pub fn validation(message: impl Display) -> Self {
+        SettingsError::Validation {
+            message: message.to_string(),
+        }
+    }
+
+    pub fn ipc(request: &str, message: impl Display) -> Self {
+        SettingsError::Ipc {
+            request: request.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for SettingsError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::Io { path, message } => write!(formatter, "Cannot access {}: {}", path, message),
+            SettingsError::Parse { path, message } => write!(formatter, "Cannot parse {}: {}", path, message),
+            SettingsError::Validation { message } => write!(formatter, "Invalid settings: {}", message),
+            SettingsError::Ipc { request, message } => write!(formatter, "Hyprland rejected {}: {}", request, message),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
\ No newline at end of file
@@ -0,0 +1,73 @@
+// Single settings are addressed by dotted paths over the serialized yaml form of the settings
+// (e.g. "appearance.rounding"). The first segment may omit the "_settings" suffix of the top level fields.
+// Changed settings are deserialized again, so values that do not fit the field type are rejected.
+
+use serde_yaml::Value;
+use crate::models::settings::hyprland_settings::HyprlandSettings;
+use crate::persistence::settings_error::SettingsError;
+
+const PATH_SEPARATOR: char = '.';
+const SETTINGS_SUFFIX: &str = "_settings";
+
+pub struct SettingsPath;
+
+impl SettingsPath {
+    pub fn get_value(settings: &HyprlandSettings, path: &str) -> Result<Value, SettingsError> {
+        let mut settings_document = Self::serialize_settings(settings)?;
+        Self::find_field(&mut settings_document, path)
+            .map(|field| field.clone())
+            .ok_or(SettingsError::validation(format!("Unknown setting {}", path)))
+    }
+
+    pub fn set_value(settings: &HyprlandSettings, path: &str, value: &str) -> Result<HyprlandSettings, SettingsError> {
+        let mut settings_document = Self::serialize_settings(settings)?;
+        let field = Self::find_field(&mut settings_document, path)
+            .ok_or(SettingsError::validation(format!("Unknown setting {}", path)))?;
+        *field = Self::parse_value(field, value)?;
+
+        serde_yaml::from_value(settings_document)
+            .map_err(|error| SettingsError::validation(format!("Invalid value {} for {}: {}", value, path, error)))
+    }
+
+    pub fn format_value(value: &Value) -> Result<String, SettingsError> {
+        match value {
+            Value::Null => Ok(String::new()),
+            Value::Bool(bool_value) => Ok(bool_value.to_string()),
+            Value::Number(number_value) => Ok(number_value.to_string()),
+            Value::String(string_value) => Ok(string_value.clone()),
+            _ => serde_yaml::to_string(value)
+                .map(|yaml_value| yaml_value.trim_end().to_string())
+                .map_err(SettingsError::validation),
+        }
+    }
+
+    fn serialize_settings(settings: &HyprlandSettings) -> Result<Value, SettingsError> {
+        serde_yaml::to_value(settings).map_err(SettingsError::validation)
+    }
+
+    fn find_field<'a>(settings_document: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+        let mut field = settings_document;
+        for (segment_index, segment) in path.split(PATH_SEPARATOR).enumerate() {
+            let fields = field.as_mapping_mut()?;
+            let field_name = if segment_index == 0 && !fields.contains_key(segment) {
+                format!("{}{}", segment, SETTINGS_SUFFIX)
+            } else {
+                segment.to_string()
+            };
+
+            field = fields.get_mut(field_name.as_str())?;
+        }
+
+        Some(field)
+    }
+
+    fn parse_value(field: &Value, value: &str) -> Result<Value, SettingsError> {
+        // String fields take the raw value, so e.g. a keyboard layout of "no" does not turn into a bool
+        if field.is_string() {
+            return Ok(Value::String(value.to_string()));
+        }
+
+        serde_yaml::from_str(value)
+            .map_err(|error| SettingsError::validation(format!("Cannot parse value {}: {}", value, error)))
+    }
+}
\ No newline at end of file
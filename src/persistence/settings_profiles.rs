@@ -0,0 +1,142 @@
+// Profiles are complete hyprsettings.yaml documents stored side by side in the profile directory, one file per
+// profile. The hyprsettings.yaml in the config directory always holds the settings of the active profile, whose name is
+// kept in a separate file, so the other commands keep working without knowing about profiles.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use crate::models::settings::hyprland_settings::HyprlandSettings;
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_reader::SettingsReader;
+use crate::persistence::yaml_settings_reader::YamlSettingsReader;
+
+pub const PROFILE_DIRECTORY_NAME: &str = "hyprsettings-profiles";
+
+const ACTIVE_PROFILE_FILE_NAME: &str = "active-profile";
+const PROFILE_FILE_EXTENSION: &str = "yaml";
+
+pub struct SettingsProfiles;
+
+impl SettingsProfiles {
+    pub fn get_profiles_directory() -> PathBuf {
+        PathBuf::from(HyprlandWriterUtils::create_hyprland_config_path(PROFILE_DIRECTORY_NAME))
+    }
+
+    pub fn get_profile_names() -> Vec<String> {
+        let Ok(profile_entries) = fs::read_dir(Self::get_profiles_directory()) else {
+            return Vec::new();
+        };
+
+        let mut profile_names: Vec<String> = profile_entries
+            .filter_map(|profile_entry| profile_entry.ok())
+            .map(|profile_entry| profile_entry.path())
+            .filter(|profile_path| {
+                profile_path.is_file()
+                    && profile_path.extension().is_some_and(|extension| extension == PROFILE_FILE_EXTENSION)
+            })
+            .filter_map(|profile_path| {
+                profile_path.file_stem().map(|profile_name| profile_name.to_string_lossy().to_string())
+            })
+            .collect();
+
+        profile_names.sort();
+        profile_names
+    }
+
+    pub fn get_active_profile() -> Option<String> {
+        Self::read_active_profile_name()
+            .filter(|active_profile| Self::profile_exists(active_profile))
+    }
+
+    pub fn set_active_profile(profile_name: &str) -> Result<(), SettingsError> {
+        let active_profile_path = Self::get_profiles_directory().join(ACTIVE_PROFILE_FILE_NAME);
+        HyprlandWriterUtils::write_content_atomically(&active_profile_path.to_string_lossy(), profile_name.as_bytes())
+    }
+
+    pub fn profile_exists(profile_name: &str) -> bool {
+        !profile_name.is_empty() && Self::get_profile_path(profile_name).is_file()
+    }
+
+    pub fn validate_profile_name(profile_name: &str) -> Result<(), SettingsError> {
+        let is_valid_name = profile_name.chars()
+            .all(|character| character.is_alphanumeric() || "-_ ".contains(character));
+
+        if profile_name.trim().is_empty() || !is_valid_name {
+            return Err(SettingsError::validation(format!(
+                "\"{}\" is not a valid profile name, use letters, digits, spaces, - and _", profile_name
+            )));
+        }
+
+        if profile_name != profile_name.trim() {
+            return Err(SettingsError::validation("A profile name must not start or end with a space"));
+        }
+
+        Ok(())
+    }
+
+    pub fn load_profile(profile_name: &str) -> Result<HyprlandSettings, SettingsError> {
+        let profile_path = Self::get_profile_path(profile_name);
+        let mut reader = YamlSettingsReader::from_file(&profile_path.to_string_lossy());
+        reader.read_from_config()?;
+        Ok(reader.deserialize_settings())
+    }
+
+    pub fn save_profile(profile_name: &str, settings: &HyprlandSettings) -> Result<(), SettingsError> {
+        Self::validate_profile_name(profile_name)?;
+
+        let profile_path = Self::get_profile_path(profile_name).to_string_lossy().to_string();
+        let serialized_settings = serde_yaml::to_string(settings)
+            .map_err(|error| SettingsError::parse(&profile_path, error))?;
+        HyprlandWriterUtils::write_content_atomically(&profile_path, serialized_settings.as_bytes())
+    }
+
+    pub fn create_profile(profile_name: &str, settings: &HyprlandSettings) -> Result<(), SettingsError> {
+        if Self::profile_exists(profile_name) {
+            return Err(SettingsError::validation(format!("The profile \"{}\" already exists", profile_name)));
+        }
+
+        Self::save_profile(profile_name, settings)
+    }
+
+    pub fn rename_profile(profile_name: &str, new_profile_name: &str) -> Result<(), SettingsError> {
+        let settings = Self::load_profile(profile_name)?;
+        Self::create_profile(new_profile_name, &settings)?;
+        Self::delete_profile_file(profile_name)?;
+
+        if Self::read_active_profile_name().as_deref() == Some(profile_name) {
+            Self::set_active_profile(new_profile_name)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn delete_profile(profile_name: &str) -> Result<(), SettingsError> {
+        if Self::get_active_profile().as_deref() == Some(profile_name) {
+            return Err(SettingsError::validation(format!(
+                "The profile \"{}\" is active, activate another profile before deleting it", profile_name
+            )));
+        }
+
+        Self::delete_profile_file(profile_name)
+    }
+
+    fn delete_profile_file(profile_name: &str) -> Result<(), SettingsError> {
+        let profile_path = Self::get_profile_path(profile_name);
+        match fs::remove_file(&profile_path) {
+            Ok(_) => Ok(()),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(SettingsError::io(&profile_path.to_string_lossy(), error)),
+        }
+    }
+
+    fn read_active_profile_name() -> Option<String> {
+        let active_profile_path = Self::get_profiles_directory().join(ACTIVE_PROFILE_FILE_NAME);
+        fs::read_to_string(active_profile_path).ok()
+            .map(|active_profile| active_profile.trim().to_string())
+    }
+
+    fn get_profile_path(profile_name: &str) -> PathBuf {
+        Self::get_profiles_directory().join(format!("{}.{}", profile_name, PROFILE_FILE_EXTENSION))
+    }
+}
\ No newline at end of file
@@ -1,4 +1,6 @@
+use crate::persistence::settings_error::SettingsError;
+
 pub trait SettingsReader<Config> {
-    fn read_from_config(&mut self);
+    fn read_from_config(&mut self) -> Result<(), SettingsError>;
     fn deserialize_settings(&mut self) -> Config;
 }
\ No newline at end of file
@@ -0,0 +1,145 @@
+// Checks the settings before they are saved. Every diagnostic names the dotted path of the offending field
+// (the same paths the get and set commands use), so the pages can highlight the matching controls.
+// Errors block saving, warnings are only shown.
+
+use std::path::Path;
+use crate::models::monitor::monitor_configuration::MonitorConfiguration;
+use crate::models::monitor::video_mode::VideoModePreset;
+use crate::models::settings::appearance_settings::AppearanceSettings;
+use crate::models::settings::hyprland_settings::HyprlandSettings;
+use crate::models::settings::input_settings::InputSettings;
+use crate::models::settings::monitor_settings::MonitorSettings;
+use crate::persistence::settings_diagnostic::SettingsDiagnostic;
+
+pub struct SettingsValidator;
+
+impl SettingsValidator {
+    pub fn validate(settings: &HyprlandSettings) -> Vec<SettingsDiagnostic> {
+        let mut diagnostics = Vec::new();
+        Self::validate_input_settings(&settings.input_settings, &mut diagnostics);
+        Self::validate_appearance_settings(&settings.appearance_settings, &mut diagnostics);
+        Self::validate_monitor_settings(&settings.monitor_settings, &mut diagnostics);
+        diagnostics
+    }
+
+    pub fn has_errors(diagnostics: &[SettingsDiagnostic]) -> bool {
+        diagnostics.iter().any(|diagnostic| diagnostic.is_error())
+    }
+
+    pub fn get_monitor_field_path(monitor_port: &str, field_name: &str) -> String {
+        format!("monitor_settings.monitor_configurations.{}.{}", monitor_port, field_name)
+    }
+
+    fn validate_input_settings(input_settings: &InputSettings, diagnostics: &mut Vec<SettingsDiagnostic>) {
+        if input_settings.keyboard_layout.trim().is_empty() {
+            diagnostics.push(SettingsDiagnostic::error(
+                "input_settings.keyboard_layout", "The keyboard layout must not be empty."
+            ));
+        }
+    }
+
+    fn validate_appearance_settings(appearance_settings: &AppearanceSettings, diagnostics: &mut Vec<SettingsDiagnostic>) {
+        if !appearance_settings.wallpaper_path.is_empty() && !Self::file_exists(&appearance_settings.wallpaper_path) {
+            diagnostics.push(SettingsDiagnostic::warning(
+                "appearance_settings.wallpaper_path",
+                format!("The wallpaper {} does not exist.", appearance_settings.wallpaper_path)
+            ));
+        }
+
+        for (monitor_port, monitor_wallpaper_path) in &appearance_settings.monitor_wallpapers {
+            if !Self::file_exists(monitor_wallpaper_path) {
+                diagnostics.push(SettingsDiagnostic::warning(
+                    &format!("appearance_settings.monitor_wallpapers.{}", monitor_port),
+                    format!("The wallpaper {} does not exist.", monitor_wallpaper_path)
+                ));
+            }
+        }
+
+        let opacities = [
+            ("appearance_settings.active_opacity", appearance_settings.active_opacity),
+            ("appearance_settings.inactive_opacity", appearance_settings.inactive_opacity),
+        ];
+
+        for (field_path, opacity) in opacities {
+            if !(0.0..=1.0).contains(&opacity) {
+                diagnostics.push(SettingsDiagnostic::error(
+                    field_path, format!("The opacity {} is not between 0 and 1.", opacity)
+                ));
+            }
+        }
+    }
+
+    fn validate_monitor_settings(monitor_settings: &MonitorSettings, diagnostics: &mut Vec<SettingsDiagnostic>) {
+        let enabled_monitors: Vec<(&String, &MonitorConfiguration)> = monitor_settings.monitor_configurations
+            .iter()
+            .filter(|(_, monitor_configuration)| monitor_configuration.enabled)
+            .collect();
+
+        for (monitor_port, monitor_configuration) in &enabled_monitors {
+            Self::validate_video_mode(monitor_port, monitor_configuration, diagnostics);
+
+            if monitor_configuration.resolution_scale <= 0.0 {
+                diagnostics.push(SettingsDiagnostic::error(
+                    &Self::get_monitor_field_path(monitor_port, "resolution_scale"),
+                    format!("The scale of {} must be greater than 0.", monitor_port)
+                ));
+            }
+        }
+
+        for (monitor_index, (monitor_port, monitor_configuration)) in enabled_monitors.iter().enumerate() {
+            for (other_monitor_port, other_monitor_configuration) in &enabled_monitors[monitor_index + 1..] {
+                if !monitor_configuration.overlaps(other_monitor_configuration) {
+                    continue;
+                }
+
+                let overlap_message = format!("The monitors {} and {} overlap.", monitor_port, other_monitor_port);
+                diagnostics.push(SettingsDiagnostic::error(
+                    &Self::get_monitor_field_path(monitor_port, "offset"), &overlap_message
+                ));
+                diagnostics.push(SettingsDiagnostic::error(
+                    &Self::get_monitor_field_path(other_monitor_port, "offset"), &overlap_message
+                ));
+            }
+        }
+    }
+
+    fn validate_video_mode(
+        monitor_port: &str, monitor_configuration: &MonitorConfiguration, diagnostics: &mut Vec<SettingsDiagnostic>
+    ) {
+        // Presets are resolved by Hyprland itself, so there is no concrete mode to check
+        if monitor_configuration.video_mode_preset != VideoModePreset::Custom {
+            return;
+        }
+
+        let video_mode = &monitor_configuration.video_mode;
+        if video_mode.refresh_rate <= 0.0 {
+            diagnostics.push(SettingsDiagnostic::error(
+                &Self::get_monitor_field_path(monitor_port, "video_mode.refresh_rate"),
+                format!("The refresh rate of {} must be greater than 0.", monitor_port)
+            ));
+        }
+
+        if video_mode.width_resolution == 0 || video_mode.height_resolution == 0 {
+            diagnostics.push(SettingsDiagnostic::error(
+                &Self::get_monitor_field_path(monitor_port, "video_mode"),
+                format!("The resolution of {} must be greater than 0.", monitor_port)
+            ));
+        } else if !monitor_configuration.information.video_modes.is_empty()
+            && !monitor_configuration.information.has_video_mode(video_mode)
+        {
+            diagnostics.push(SettingsDiagnostic::warning(
+                &Self::get_monitor_field_path(monitor_port, "video_mode"),
+                format!("The mode {} is not advertised by {}.", video_mode, monitor_port)
+            ));
+        }
+    }
+
+    fn file_exists(file_path: &str) -> bool {
+        let file_path = match file_path.strip_prefix("~/") {
+            Some(home_relative_path) => Path::new(&std::env::var("HOME").unwrap_or_default()).join(home_relative_path),
+            None => Path::new(file_path).to_path_buf(),
+        };
+
+        file_path.exists()
+    }
+}
\ No newline at end of file
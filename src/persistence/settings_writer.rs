@@ -1,4 +1,39 @@
+use std::fs;
+use std::path::Path;
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+use crate::persistence::settings_error::SettingsError;
+
+#[derive(Debug, Clone)]
+pub struct RenderedConfig {
+    pub config_path: String,
+    pub content: String,
+}
+
+impl RenderedConfig {
+    pub fn get_config_name(&self) -> String {
+        Path::new(&self.config_path).file_name()
+            .map(|config_name| config_name.to_string_lossy().to_string())
+            .unwrap_or(self.config_path.clone())
+    }
+
+    pub fn read_current_content(&self) -> String {
+        fs::read_to_string(&self.config_path).unwrap_or_default()
+    }
+
+    pub fn write_to_file(&self) -> Result<(), SettingsError> {
+        HyprlandWriterUtils::write_content_atomically(self.config_path.as_str(), self.content.as_bytes())
+    }
+}
+
 pub trait SettingsWriter<Settings> {
-    fn serialize_settings(&mut self, settings: Settings);
-    fn write_to_config(&self);
+    fn serialize_settings(&mut self, settings: Settings) -> Result<(), SettingsError>;
+    fn render_configs(&self) -> Result<Vec<RenderedConfig>, SettingsError>;
+
+    fn write_to_config(&self) -> Result<(), SettingsError> {
+        for rendered_config in self.render_configs()? {
+            rendered_config.write_to_file()?;
+        }
+
+        Ok(())
+    }
 }
\ No newline at end of file
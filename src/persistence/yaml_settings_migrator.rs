@@ -0,0 +1,171 @@
+// Older hyprsettings.yaml documents are upgraded on the raw YAML tree before they are deserialized, so renamed or
+// restructured fields keep their values. Documents without a schema_version are treated as version 0 and every
+// migration step upgrades a document by exactly one version.
+
+use std::path::Path;
+use serde_yaml::{Mapping, Value};
+use crate::models::settings::hyprland_settings::CURRENT_SCHEMA_VERSION;
+use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
+use crate::persistence::settings_error::SettingsError;
+
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+const LOCKSCREEN_POSITION_KEYS: [&str; 4] = [
+    "input_x_position", "input_y_position", "display_text_x_position", "display_text_y_position"
+];
+
+type Migration = fn(&mut Mapping);
+
+const MIGRATIONS: [Migration; CURRENT_SCHEMA_VERSION as usize] = [
+    YamlSettingsMigrator::migrate_to_version_1,
+    YamlSettingsMigrator::migrate_to_version_2,
+];
+
+pub struct YamlSettingsMigrator;
+
+impl YamlSettingsMigrator {
+    pub fn get_schema_version(document: &Value) -> u32 {
+        document.get(SCHEMA_VERSION_KEY)
+            .and_then(|schema_version| schema_version.as_u64())
+            .map(|schema_version| schema_version as u32)
+            .unwrap_or(0)
+    }
+
+    pub fn migrate_config_file(
+        config_file_path: &str, content: &str, is_backup_enabled: bool
+    ) -> Result<Value, SettingsError> {
+        let mut document: Value = serde_yaml::from_str(content)
+            .map_err(|error| SettingsError::parse(config_file_path, error))?;
+
+        if document.is_null() {
+            document = Value::Mapping(Mapping::new());
+        }
+
+        let schema_version = Self::get_schema_version(&document);
+        if schema_version > CURRENT_SCHEMA_VERSION {
+            return Err(SettingsError::validation(format!(
+                "{} uses schema version {}, but this version of HyprSettings only supports up to version {}",
+                config_file_path, schema_version, CURRENT_SCHEMA_VERSION
+            )));
+        }
+
+        if schema_version < CURRENT_SCHEMA_VERSION {
+            if is_backup_enabled {
+                Self::backup_original_config_file(config_file_path, content, schema_version)?;
+            }
+            Self::migrate(&mut document, schema_version);
+        }
+
+        Ok(document)
+    }
+
+    pub fn migrate(document: &mut Value, schema_version: u32) {
+        let Some(document_mapping) = document.as_mapping_mut() else {
+            return;
+        };
+
+        for migration in MIGRATIONS.iter().skip(schema_version as usize) {
+            migration(document_mapping);
+        }
+
+        document_mapping.insert(
+            Value::String(SCHEMA_VERSION_KEY.to_string()), Value::Number(CURRENT_SCHEMA_VERSION.into())
+        );
+    }
+
+    pub fn get_backup_file_path(config_file_path: &str, schema_version: u32) -> String {
+        format!("{}.schema-v{}.bak", config_file_path, schema_version)
+    }
+
+    fn backup_original_config_file(config_file_path: &str, content: &str, schema_version: u32) -> Result<(), SettingsError> {
+        let backup_file_path = Self::get_backup_file_path(config_file_path, schema_version);
+        if Path::new(&backup_file_path).exists() {
+            return Ok(());
+        }
+
+        HyprlandWriterUtils::write_content_atomically(backup_file_path.as_str(), content.as_bytes())
+    }
+
+    fn migrate_to_version_1(document: &mut Mapping) {
+        if let Some(appearance_settings) = Self::get_section_mut(document, "appearance_settings") {
+            Self::rename_key(appearance_settings, "inner_gab", "inner_gap");
+            Self::rename_key(appearance_settings, "outer_gab", "outer_gap");
+        }
+    }
+
+    // Lockscreen positions became signed, unsigned values beyond the signed range are clamped
+    fn migrate_to_version_2(document: &mut Mapping) {
+        let Some(lockscreen_settings) = Self::get_section_mut(document, "lockscreen_settings") else {
+            return;
+        };
+
+        for position_key in LOCKSCREEN_POSITION_KEYS {
+            if let Some(position) = lockscreen_settings.get(position_key).and_then(|position| position.as_u64())
+                && position > i32::MAX as u64
+            {
+                lockscreen_settings.insert(Value::String(position_key.to_string()), Value::Number(i32::MAX.into()));
+            }
+        }
+    }
+
+    fn get_section_mut<'a>(document: &'a mut Mapping, section_name: &str) -> Option<&'a mut Mapping> {
+        document.get_mut(section_name).and_then(|section| section.as_mapping_mut())
+    }
+
+    fn rename_key(mapping: &mut Mapping, old_key: &str, new_key: &str) {
+        if mapping.contains_key(new_key) {
+            return;
+        }
+
+        if let Some(value) = mapping.remove(old_key) {
+            mapping.insert(Value::String(new_key.to_string()), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate(content: &str) -> Value {
+        let mut document: Value = serde_yaml::from_str(content).unwrap();
+        let schema_version = YamlSettingsMigrator::get_schema_version(&document);
+        YamlSettingsMigrator::migrate(&mut document, schema_version);
+        document
+    }
+
+    #[test]
+    fn version_1_renames_gap_keys() {
+        let document = migrate("appearance_settings:\n  inner_gab: 5\n  outer_gab: 10\n");
+
+        assert_eq!(document["appearance_settings"]["inner_gap"], Value::from(5));
+        assert_eq!(document["appearance_settings"]["outer_gap"], Value::from(10));
+        assert!(document["appearance_settings"].get("inner_gab").is_none());
+        assert_eq!(YamlSettingsMigrator::get_schema_version(&document), CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn version_1_keeps_existing_gap_keys() {
+        let document = migrate("appearance_settings:\n  inner_gab: 5\n  inner_gap: 8\n");
+
+        assert_eq!(document["appearance_settings"]["inner_gap"], Value::from(8));
+    }
+
+    #[test]
+    fn version_2_clamps_lockscreen_positions() {
+        let document = migrate(
+            "schema_version: 1\nlockscreen_settings:\n  input_x_position: 4294967295\n  input_y_position: 20\n"
+        );
+
+        assert_eq!(document["lockscreen_settings"]["input_x_position"], Value::from(i32::MAX));
+        assert_eq!(document["lockscreen_settings"]["input_y_position"], Value::from(20));
+    }
+
+    #[test]
+    fn current_version_is_left_unchanged() {
+        let content = format!("schema_version: {}\nappearance_settings:\n  inner_gab: 5\n", CURRENT_SCHEMA_VERSION);
+        let document = migrate(&content);
+
+        assert_eq!(document["appearance_settings"]["inner_gab"], Value::from(5));
+        assert!(document["appearance_settings"].get("inner_gap").is_none());
+    }
+}
\ No newline at end of file
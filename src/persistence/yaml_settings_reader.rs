@@ -1,20 +1,30 @@
 use std::fs;
+use crate::persistence::settings_error::SettingsError;
 use crate::persistence::settings_reader::SettingsReader;
+use crate::persistence::yaml_settings_migrator::YamlSettingsMigrator;
 use crate::models::settings::hyprland_settings::HyprlandSettings;
 use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
 
 pub struct YamlSettingsReader {
+    config_file_path: Option<String>,
+    is_migration_backup_enabled: bool,
     deserialized_settings: HyprlandSettings
 }
 
 impl SettingsReader<HyprlandSettings> for YamlSettingsReader {
-    fn read_from_config(&mut self) {
-        let hyprsettings_config_file = HyprlandWriterUtils::create_hyprland_config_path("hyprsettings.yaml");
-        let hyprsettings_config_file_content = fs::read_to_string(hyprsettings_config_file)
-            .expect("Cannot create YAML file.");
+    fn read_from_config(&mut self) -> Result<(), SettingsError> {
+        let hyprsettings_config_file = self.config_file_path.clone()
+            .unwrap_or(HyprlandWriterUtils::create_hyprland_config_path("hyprsettings.yaml"));
+        let hyprsettings_config_file_content = fs::read_to_string(&hyprsettings_config_file)
+            .map_err(|error| SettingsError::io(&hyprsettings_config_file, error))?;
+
+        let hyprsettings_document = YamlSettingsMigrator::migrate_config_file(
+            &hyprsettings_config_file, hyprsettings_config_file_content.as_str(), self.is_migration_backup_enabled
+        )?;
 
-        self.deserialized_settings = serde_yaml::from_str(hyprsettings_config_file_content.as_str())
-            .expect("Cannot deserialize YAML file.");
+        self.deserialized_settings = serde_yaml::from_value(hyprsettings_document)
+            .map_err(|error| SettingsError::parse(&hyprsettings_config_file, error))?;
+        Ok(())
     }
 
     fn deserialize_settings(&mut self) -> HyprlandSettings {
@@ -25,16 +35,26 @@ impl SettingsReader<HyprlandSettings> for YamlSettingsReader {
 impl YamlSettingsReader {
     pub fn new() -> Self {
         Self {
+            config_file_path: None,
+            is_migration_backup_enabled: true,
+            deserialized_settings: Default::default()
+        }
+    }
+
+    pub fn from_file(config_file_path: &str) -> Self {
+        Self {
+            config_file_path: Some(config_file_path.to_string()),
+            is_migration_backup_enabled: true,
             deserialized_settings: Default::default()
         }
     }
 
+    pub fn disable_migration_backup(&mut self) {
+        self.is_migration_backup_enabled = false;
+    }
+
     pub fn config_file_exists() -> bool {
         let hyprsettings_config_file = HyprlandWriterUtils::create_hyprland_config_path("hyprsettings.yaml");
-        if fs::exists(hyprsettings_config_file).expect("Cannot verify existence of settings file") {
-            true
-        } else {
-            false
-        }
+        fs::exists(hyprsettings_config_file).unwrap_or(false)
     }
 }
\ No newline at end of file
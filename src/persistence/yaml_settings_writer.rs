@@ -1,6 +1,5 @@
-use std::fs::File;
-use std::io::Write;
-use crate::persistence::settings_writer::SettingsWriter;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_writer::{RenderedConfig, SettingsWriter};
 use crate::models::settings::hyprland_settings::HyprlandSettings;
 use crate::persistence::hyprland_writer_utils::HyprlandWriterUtils;
 
@@ -9,21 +8,22 @@ pub struct YamlSettingsWriter {
 }
 
 impl SettingsWriter<HyprlandSettings> for YamlSettingsWriter {
-    fn serialize_settings(&mut self, settings: HyprlandSettings) {
-        let serialized_settings = serde_yaml::to_string(&settings).expect("Cannot serialize settings to YAML formatted string.");
+    fn serialize_settings(&mut self, settings: HyprlandSettings) -> Result<(), SettingsError> {
+        let serialized_settings = serde_yaml::to_string(&settings)
+            .map_err(|error| SettingsError::parse(Self::get_config_path().as_str(), error))?;
         self.serialized_settings = serialized_settings;
+        Ok(())
     }
 
-    fn write_to_config(&self) {
+    fn render_configs(&self) -> Result<Vec<RenderedConfig>, SettingsError> {
         if self.serialized_settings.is_empty() {
-            return;
+            return Ok(Vec::new());
         }
-        
-        let hyprsettings_config_file_path = HyprlandWriterUtils::create_hyprland_config_path("hyprsettings.yaml");
-        let mut yaml_file = File::create(hyprsettings_config_file_path)
-            .expect("Cannot create YAML file.");
-    
-        yaml_file.write_all(self.serialized_settings.as_bytes()).expect("Cannot write settings as bytes to YAML file.");
+
+        Ok(vec![RenderedConfig {
+            config_path: Self::get_config_path(),
+            content: self.serialized_settings.clone(),
+        }])
     }
 }
 
@@ -33,4 +33,8 @@ impl YamlSettingsWriter {
             serialized_settings: "".to_string(),
         }
     }
+
+    fn get_config_path() -> String {
+        HyprlandWriterUtils::create_hyprland_config_path("hyprsettings.yaml")
+    }
 }
\ No newline at end of file
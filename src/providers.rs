@@ -1,7 +1,12 @@
 pub mod application_provider;
 pub mod module_provider;
 pub mod monitor_provider;
+pub mod monitor_backend;
+pub mod hyprctl_monitor_backend;
+pub mod wlr_randr_monitor_backend;
 pub mod keybind_provider;
 pub mod lockscreen_provider;
 pub mod appearance_provider;
 pub mod input_provider;
+pub mod backup_provider;
+pub mod config_file_provider;
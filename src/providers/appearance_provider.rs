@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use crate::models::rgba_color::RGBAColor;
 use crate::models::settings::appearance_settings::AppearanceSettings;
 
@@ -20,6 +21,26 @@ impl AppearanceProvider {
         self.settings.wallpaper_path.clone()
     }
 
+    pub fn get_monitor_wallpapers(&self) -> BTreeMap<String, String> {
+        self.settings.monitor_wallpapers.clone()
+    }
+
+    pub fn set_wallpaper_splash(&mut self, state: bool) {
+        self.settings.wallpaper_splash = state;
+    }
+
+    pub fn get_wallpaper_splash(&self) -> bool {
+        self.settings.wallpaper_splash
+    }
+
+    pub fn set_disable_wallpaper_ipc(&mut self, state: bool) {
+        self.settings.disable_wallpaper_ipc = state;
+    }
+
+    pub fn get_disable_wallpaper_ipc(&self) -> bool {
+        self.settings.disable_wallpaper_ipc
+    }
+
     pub fn set_cursor_size(&mut self, size: u32) {
         self.settings.cursor_size = size;
     }
@@ -52,20 +73,20 @@ impl AppearanceProvider {
         self.settings.disable_hyprland_logo
     }
 
-    pub fn set_inner_gab(&mut self, gab: f64) {
-        self.settings.inner_gab = gab;
+    pub fn set_inner_gap(&mut self, gap: f64) {
+        self.settings.inner_gap = gap;
     }
 
-    pub fn get_inner_gab(&self) -> f64 {
-        self.settings.inner_gab
+    pub fn get_inner_gap(&self) -> f64 {
+        self.settings.inner_gap
     }
 
-    pub fn set_outer_gab(&mut self, gab: f64) {
-        self.settings.outer_gab = gab;
+    pub fn set_outer_gap(&mut self, gap: f64) {
+        self.settings.outer_gap = gap;
     }
 
-    pub fn get_outer_gab(&self) -> f64 {
-        self.settings.outer_gab
+    pub fn get_outer_gap(&self) -> f64 {
+        self.settings.outer_gap
     }
 
     pub fn set_border_size(&mut self, size: f64) {
@@ -244,6 +265,10 @@ impl AppearanceProvider {
         self.settings.split_preservation
     }
     
+    pub fn set_settings(&mut self, settings: AppearanceSettings) {
+        self.settings = settings;
+    }
+
     pub fn get_settings(&self) -> AppearanceSettings {
         self.settings.clone()   
     }
@@ -1,10 +1,18 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 use crate::providers::appearance_provider::AppearanceProvider;
+use crate::providers::backup_provider::BackupProvider;
+use crate::providers::config_file_provider::ConfigFileProvider;
 use crate::providers::monitor_provider::MonitorProvider;
 use crate::models::settings::appearance_settings::AppearanceSettings;
+use crate::models::settings::backup_settings::BackupSettings;
+use crate::models::settings::config_file_settings::ConfigFileSettings;
+use crate::persistence::settings_error::SettingsError;
 use crate::persistence::settings_reader::SettingsReader;
 use crate::persistence::yaml_settings_reader::YamlSettingsReader;
+use crate::persistence::hyprland_config_reader::HyprlandConfigReader;
+use crate::persistence::hyprlock_config_reader::HyprlockConfigReader;
+use crate::persistence::hyprpaper_config_reader::HyprpaperConfigReader;
 use crate::models::settings::monitor_settings::MonitorSettings;
 use crate::models::settings::hyprland_settings::HyprlandSettings;
 use crate::models::settings::input_settings::InputSettings;
@@ -25,21 +33,28 @@ pub struct ApplicationProvider {
     lockscreen_provider: RcMut<LockscreenProvider>,
     input_provider: RcMut<InputProvider>,
     keybind_provider: RcMut<KeybindProvider>,
+    backup_provider: RcMut<BackupProvider>,
+    config_file_provider: RcMut<ConfigFileProvider>,
+    settings_errors: Vec<SettingsError>,
+    yaml_settings_error: RcMut<Option<SettingsError>>,
 }
 
 impl ApplicationProvider {
     pub fn new() -> Self {
-        let hyprland_settings = Self::get_config_settings();
+        let mut settings_errors = Vec::new();
+        let mut yaml_settings_error = None;
+        let hyprland_settings = Self::get_config_settings(&mut settings_errors, &mut yaml_settings_error);
         let module_provider = Self::create_module_provider(&hyprland_settings);
-        let has_wlrrandr_module = module_provider
-            .borrow()
-            .has_dependency_module("wlr-randr".to_string());
-
-        let monitor_provider = Self::create_monitor_provider(&hyprland_settings, has_wlrrandr_module);
-        let appearance_provider = Self::create_appearance_provider(&hyprland_settings);
-        let lockscreen_provider = Self::create_lockscreen_provider(&hyprland_settings);
+        let has_monitor_backend = MonitorProvider::has_monitor_backend();
+        let monitor_provider = Self::create_monitor_provider(&hyprland_settings, has_monitor_backend);
+        let appearance_provider = Self::create_appearance_provider(&hyprland_settings, &mut settings_errors);
+        let lockscreen_provider = Self::create_lockscreen_provider(
+            &hyprland_settings, yaml_settings_error.is_none(), &mut settings_errors
+        );
         let keybind_provider = Self::create_keybind_provider(&hyprland_settings);
         let input_provider = Self::create_input_provider(&hyprland_settings);
+        let backup_provider = Self::create_backup_provider(&hyprland_settings);
+        let config_file_provider = Self::create_config_file_provider(&hyprland_settings);
 
         Self {
             module_provider,
@@ -48,6 +63,10 @@ impl ApplicationProvider {
             lockscreen_provider,
             input_provider,
             keybind_provider,
+            backup_provider,
+            config_file_provider,
+            settings_errors,
+            yaml_settings_error: new_rc_mut(yaml_settings_error),
         }
     }
 
@@ -75,6 +94,46 @@ impl ApplicationProvider {
         self.input_provider.clone()
     }
 
+    pub fn get_backup_provider(&self) -> Rc<RefCell<BackupProvider>> {
+        self.backup_provider.clone()
+    }
+
+    pub fn get_config_file_provider(&self) -> Rc<RefCell<ConfigFileProvider>> {
+        self.config_file_provider.clone()
+    }
+
+    pub fn get_settings_errors(&self) -> Vec<SettingsError> {
+        self.settings_errors.clone()
+    }
+
+    // Saving is blocked while hyprsettings.yaml cannot be loaded, otherwise the next save would replace it
+    pub fn get_yaml_settings_error(&self) -> Option<SettingsError> {
+        self.yaml_settings_error.borrow().clone()
+    }
+
+    pub fn reload_settings(&self) -> Vec<SettingsError> {
+        let reloaded_provider = Self::new();
+        self.module_provider.borrow_mut()
+            .set_settings(reloaded_provider.module_provider.borrow().get_settings());
+        self.monitor_provider.borrow_mut()
+            .set_settings(reloaded_provider.monitor_provider.borrow().get_settings());
+        self.appearance_provider.borrow_mut()
+            .set_settings(reloaded_provider.appearance_provider.borrow().get_settings());
+        self.lockscreen_provider.borrow_mut()
+            .set_settings(reloaded_provider.lockscreen_provider.borrow().get_settings());
+        self.input_provider.borrow_mut()
+            .set_settings(reloaded_provider.input_provider.borrow().get_settings());
+        self.keybind_provider.borrow_mut()
+            .set_settings(reloaded_provider.keybind_provider.borrow().get_settings());
+        self.backup_provider.borrow_mut()
+            .set_settings(reloaded_provider.backup_provider.borrow().get_settings());
+        self.config_file_provider.borrow_mut()
+            .set_settings(reloaded_provider.config_file_provider.borrow().get_settings());
+        *self.yaml_settings_error.borrow_mut() = reloaded_provider.get_yaml_settings_error();
+
+        reloaded_provider.get_settings_errors()
+    }
+
     fn create_module_provider(settings: &Option<HyprlandSettings>) -> RcMut<ModuleProvider> {
         let mut module_provider = if let Some(settings) = settings {
             ModuleProvider::new(settings.program_settings.clone())
@@ -86,12 +145,19 @@ impl ApplicationProvider {
         new_rc_mut(module_provider)
     }
 
-    fn create_monitor_provider(settings: &Option<HyprlandSettings>, has_wlrrandr_module: bool) -> RcMut<MonitorProvider> {
-        let monitor_provider = if let Some(settings) = settings {
-            MonitorProvider::new(settings.monitor_settings.clone())
+    fn create_monitor_provider(settings: &Option<HyprlandSettings>, has_monitor_backend: bool) -> RcMut<MonitorProvider> {
+        let monitor_provider = if let Some(settings) = settings
+            && !settings.monitor_settings.monitor_configurations.is_empty()
+        {
+            let mut monitor_provider = MonitorProvider::new(settings.monitor_settings.clone());
+            if has_monitor_backend && monitor_provider.has_missing_monitor_informations() {
+                monitor_provider.fetch_monitor_informations();
+            }
+
+            monitor_provider
         } else {
             let mut monitor_provider = MonitorProvider::new(MonitorSettings::default());
-            if has_wlrrandr_module {
+            if has_monitor_backend {
                 monitor_provider.fetch_monitors();
             }
 
@@ -101,19 +167,44 @@ impl ApplicationProvider {
         new_rc_mut(monitor_provider)
     }
 
-    fn create_appearance_provider(settings: &Option<HyprlandSettings>) -> RcMut<AppearanceProvider> {
-        let appearance_provider = if let Some(settings) = settings {
-            AppearanceProvider::new(settings.appearance_settings.clone())
+    fn create_appearance_provider(
+        settings: &Option<HyprlandSettings>, settings_errors: &mut Vec<SettingsError>
+    ) -> RcMut<AppearanceProvider> {
+        let mut appearance_settings = if let Some(settings) = settings {
+            settings.appearance_settings.clone()
         } else {
-            AppearanceProvider::new(AppearanceSettings::default())
+            AppearanceSettings::default()
         };
 
+        if HyprpaperConfigReader::config_file_exists() {
+            let mut reader = HyprpaperConfigReader::new(appearance_settings.clone());
+            match reader.read_from_config() {
+                Ok(_) => appearance_settings = reader.deserialize_settings(),
+                Err(error) => settings_errors.push(error),
+            }
+        }
+
+        let appearance_provider = AppearanceProvider::new(appearance_settings);
+
         new_rc_mut(appearance_provider)
     }
 
-    fn create_lockscreen_provider(settings: &Option<HyprlandSettings>) -> RcMut<LockscreenProvider> {
-        let lockscreen_provider = if let Some(settings) = settings {
+    fn create_lockscreen_provider(
+        settings: &Option<HyprlandSettings>, is_yaml_settings_loaded: bool, settings_errors: &mut Vec<SettingsError>
+    ) -> RcMut<LockscreenProvider> {
+        let lockscreen_provider = if let Some(settings) = settings
+            && is_yaml_settings_loaded
+            && YamlSettingsReader::config_file_exists()
+        {
             LockscreenProvider::new(settings.lockscreen_settings.clone())
+        } else if HyprlockConfigReader::config_file_exists() {
+            let lockscreen_settings = Self::import_lockscreen_settings(LockScreenSettings::default())
+                .unwrap_or_else(|error| {
+                    settings_errors.push(error);
+                    LockScreenSettings::default()
+                });
+
+            LockscreenProvider::new(lockscreen_settings)
         } else {
             LockscreenProvider::new(LockScreenSettings::default())
         };
@@ -121,6 +212,12 @@ impl ApplicationProvider {
         new_rc_mut(lockscreen_provider)
     }
 
+    pub fn import_lockscreen_settings(base_settings: LockScreenSettings) -> Result<LockScreenSettings, SettingsError> {
+        let mut reader = HyprlockConfigReader::new(base_settings);
+        reader.read_from_config()?;
+        Ok(reader.deserialize_settings())
+    }
+
     fn create_keybind_provider(settings: &Option<HyprlandSettings>) -> RcMut<KeybindProvider> {
         let keybind_provider = if let Some(settings) = settings {
             KeybindProvider::new(settings.keybind_settings.clone())
@@ -141,13 +238,63 @@ impl ApplicationProvider {
         new_rc_mut(input_provider)
     }
 
-    fn get_config_settings() -> Option<HyprlandSettings> {
-        if YamlSettingsReader::config_file_exists() {
+    fn create_backup_provider(settings: &Option<HyprlandSettings>) -> RcMut<BackupProvider> {
+        let backup_provider = if let Some(settings) = settings {
+            BackupProvider::new(settings.backup_settings.clone())
+        } else {
+            BackupProvider::new(BackupSettings::default())
+        };
+
+        new_rc_mut(backup_provider)
+    }
+
+    fn create_config_file_provider(settings: &Option<HyprlandSettings>) -> RcMut<ConfigFileProvider> {
+        let config_file_provider = if let Some(settings) = settings {
+            ConfigFileProvider::new(settings.config_file_settings.clone())
+        } else {
+            ConfigFileProvider::new(ConfigFileSettings::default())
+        };
+
+        new_rc_mut(config_file_provider)
+    }
+
+    // Loads the settings like the application does, but fails on the first error, so a command line change is never
+    // written on top of settings which could only be read partially
+    pub fn read_config_settings() -> Result<HyprlandSettings, SettingsError> {
+        let mut settings_errors = Vec::new();
+        let hyprland_settings = Self::get_config_settings(&mut settings_errors, &mut None);
+        match settings_errors.into_iter().next() {
+            Some(settings_error) => Err(settings_error),
+            None => Ok(hyprland_settings.unwrap_or_default()),
+        }
+    }
+
+    fn get_config_settings(
+        settings_errors: &mut Vec<SettingsError>, yaml_settings_error: &mut Option<SettingsError>
+    ) -> Option<HyprlandSettings> {
+        let yaml_settings = if YamlSettingsReader::config_file_exists() {
             let mut reader = YamlSettingsReader::new();
-            reader.read_from_config();
-            return Some(reader.deserialize_settings())
+            match reader.read_from_config() {
+                Ok(_) => Some(reader.deserialize_settings()),
+                Err(error) => {
+                    *yaml_settings_error = Some(error.clone());
+                    settings_errors.push(error);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        if HyprlandConfigReader::config_file_exists() {
+            let base_settings = yaml_settings.clone().unwrap_or_default();
+            let mut reader = HyprlandConfigReader::new(base_settings);
+            match reader.read_from_config() {
+                Ok(_) => return Some(reader.deserialize_settings()),
+                Err(error) => settings_errors.push(error),
+            }
         }
 
-        None
+        yaml_settings
     }
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::models::settings::backup_settings::BackupSettings;
+
+pub struct BackupProvider {
+    settings: BackupSettings,
+}
+
+impl BackupProvider {
+    pub fn new(settings: BackupSettings) -> Self {
+        Self {
+            settings,
+        }
+    }
+
+    pub fn set_retention_count(&mut self, retention_count: usize) {
+        self.settings.retention_count = retention_count;
+    }
+
+    pub fn get_retention_count(&self) -> usize {
+        self.settings.retention_count
+    }
+
+    pub fn set_settings(&mut self, settings: BackupSettings) {
+        self.settings = settings;
+    }
+
+    pub fn get_settings(&self) -> BackupSettings {
+        self.settings.clone()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::models::settings::config_file_settings::ConfigFileSettings;
+
+pub struct ConfigFileProvider {
+    settings: ConfigFileSettings,
+}
+
+impl ConfigFileProvider {
+    pub fn new(settings: ConfigFileSettings) -> Self {
+        Self {
+            settings,
+        }
+    }
+
+    pub fn set_split_config_files(&mut self, split_config_files: bool) {
+        self.settings.split_config_files = split_config_files;
+    }
+
+    pub fn get_split_config_files(&self) -> bool {
+        self.settings.split_config_files
+    }
+
+    pub fn set_settings(&mut self, settings: ConfigFileSettings) {
+        self.settings = settings;
+    }
+
+    pub fn get_settings(&self) -> ConfigFileSettings {
+        self.settings.clone()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,157 @@
+use std::env;
+use serde::Deserialize;
+use crate::math::vector::Vector;
+use crate::models::monitor::monitor_configuration::{MonitorConfiguration, MonitorOrientation};
+use crate::models::monitor::monitor_information::MonitorInformation;
+use crate::models::monitor::video_mode::{VideoMode, VideoModePreset};
+use crate::persistence::settings_error::SettingsError;
+use crate::providers::monitor_backend::MonitorBackend;
+
+const INSTANCE_SIGNATURE_VARIABLE: &str = "HYPRLAND_INSTANCE_SIGNATURE";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct HyprctlMonitor {
+    name: String,
+    description: String,
+    make: String,
+    model: String,
+    serial: String,
+    width: u32,
+    height: u32,
+    refresh_rate: f32,
+    x: i32,
+    y: i32,
+    scale: f32,
+    transform: u8,
+    disabled: bool,
+    available_modes: Vec<String>,
+}
+
+pub struct HyprctlMonitorBackend;
+
+impl MonitorBackend for HyprctlMonitorBackend {
+    fn get_command_name(&self) -> &'static str {
+        "hyprctl"
+    }
+
+    fn get_command_arguments(&self) -> Vec<&'static str> {
+        vec!["monitors", "all", "-j"]
+    }
+
+    fn parse_monitor_output(&self, output: &str) -> Result<Vec<MonitorConfiguration>, SettingsError> {
+        let hyprctl_monitors: Vec<HyprctlMonitor> = serde_json::from_str(output)
+            .map_err(|error| SettingsError::parse("the output of hyprctl monitors", error))?;
+
+        let monitor_configurations = hyprctl_monitors.into_iter()
+            .filter(|hyprctl_monitor| !hyprctl_monitor.name.is_empty())
+            .map(Self::create_monitor_configuration)
+            .collect();
+        Ok(monitor_configurations)
+    }
+
+    fn is_available(&self) -> bool {
+        // hyprctl only answers inside of a running Hyprland session
+        env::var_os(INSTANCE_SIGNATURE_VARIABLE).is_some() && self.is_command_available()
+    }
+}
+
+impl HyprctlMonitorBackend {
+    fn create_monitor_configuration(hyprctl_monitor: HyprctlMonitor) -> MonitorConfiguration {
+        let video_modes: Vec<VideoMode> = hyprctl_monitor.available_modes.iter()
+            .filter_map(|available_mode| Self::parse_video_mode(available_mode))
+            .collect();
+
+        let mut information = MonitorInformation {
+            port_name: hyprctl_monitor.name,
+            brand_name: hyprctl_monitor.make,
+            model_name: hyprctl_monitor.model,
+            serial_number: hyprctl_monitor.serial,
+            description: hyprctl_monitor.description,
+            ..Default::default()
+        };
+        information.set_video_modes(video_modes);
+
+        // Disabled monitors may report an empty current mode
+        let video_mode = if hyprctl_monitor.width > 0 && hyprctl_monitor.height > 0 {
+            let current_video_mode = VideoMode {
+                width_resolution: hyprctl_monitor.width,
+                height_resolution: hyprctl_monitor.height,
+                refresh_rate: hyprctl_monitor.refresh_rate,
+            };
+
+            information.find_video_mode(&current_video_mode)
+                .cloned()
+                .unwrap_or(current_video_mode)
+        } else {
+            information.max_video_mode.clone()
+        };
+
+        let resolution_scale = if hyprctl_monitor.scale > 0.0 { hyprctl_monitor.scale } else { 1.0 };
+
+        MonitorConfiguration {
+            enabled: !hyprctl_monitor.disabled,
+            information,
+            video_mode,
+            video_mode_preset: VideoModePreset::Custom,
+            offset: Vector::new(hyprctl_monitor.x as f64, hyprctl_monitor.y as f64),
+            resolution_scale,
+            orientation: MonitorOrientation::from_hyprland_orientation_code(hyprctl_monitor.transform),
+        }
+    }
+
+    fn parse_video_mode(available_mode: &str) -> Option<VideoMode> {
+        // e.g. "2560x1440@143.91Hz"
+        let available_mode = available_mode.trim().trim_end_matches("Hz");
+        let (resolution, refresh_rate) = available_mode.split_once('@')?;
+        let (width_resolution, height_resolution) = resolution.split_once('x')?;
+
+        Some(VideoMode {
+            width_resolution: width_resolution.parse::<u32>().ok()?,
+            height_resolution: height_resolution.parse::<u32>().ok()?,
+            refresh_rate: refresh_rate.parse::<f32>().ok()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::providers::monitor_backend::tests::assert_fixture_monitors;
+    use super::*;
+
+    const HYPRCTL_MONITORS_OUTPUT: &str = include_str!(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/hyprctl_monitors_all.json")
+    );
+
+    fn parse_fixture() -> Vec<MonitorConfiguration> {
+        HyprctlMonitorBackend.parse_monitor_output(HYPRCTL_MONITORS_OUTPUT).unwrap()
+    }
+
+    #[test]
+    fn parses_fixture_monitors() {
+        assert_fixture_monitors(&parse_fixture());
+    }
+
+    #[test]
+    fn parses_all_monitors() {
+        let port_names: Vec<String> = parse_fixture().into_iter()
+            .map(|configuration| configuration.information.port_name)
+            .collect();
+
+        assert_eq!(port_names, vec!["DP-1", "HDMI-A-1", "eDP-1"]);
+    }
+
+    #[test]
+    fn parses_available_modes() {
+        let configuration = &parse_fixture()[0];
+
+        assert_eq!(configuration.information.max_video_mode.to_string(), "2560x1440@74.97Hz");
+    }
+
+    #[test]
+    fn invalid_output_is_a_parse_error() {
+        let result = HyprctlMonitorBackend.parse_monitor_output("unknown request");
+
+        assert!(matches!(result, Err(SettingsError::Parse { .. })));
+    }
+}
\ No newline at end of file
@@ -172,6 +172,10 @@ impl InputProvider {
         self.settings.tablet_active_y.clone()
     }
 
+    pub fn set_settings(&mut self, settings: InputSettings) {
+        self.settings = settings;
+    }
+
     pub fn get_settings(&self) -> InputSettings {
         self.settings.clone()
     }
@@ -32,6 +32,10 @@ impl KeybindProvider {
         }
     }
 
+    pub fn get_custom_keybind(&self, custom_keybind_name: &str) -> Option<CustomKeybind> {
+        self.settings.custom_keybinds.get(custom_keybind_name).cloned()
+    }
+
     pub fn remove_custom_keybind(&mut self, custom_keybind_name: String) {
         self.settings.custom_keybinds.remove(&custom_keybind_name);
     }
@@ -44,6 +48,10 @@ impl KeybindProvider {
             .collect()
     }
     
+    pub fn set_settings(&mut self, settings: KeyBindSettings) {
+        self.settings = settings;
+    }
+
     pub fn get_settings(&self) -> KeyBindSettings {
         self.settings.clone()
     }
@@ -190,19 +190,19 @@ impl LockscreenProvider {
         self.settings.hide_input
     }
 
-    pub fn set_input_x_position(&mut self, position: u32) {
+    pub fn set_input_x_position(&mut self, position: i32) {
         self.settings.input_x_position = position;
     }
 
-    pub fn get_input_x_position(&self) -> u32 {
+    pub fn get_input_x_position(&self) -> i32 {
         self.settings.input_x_position
     }
 
-    pub fn set_input_y_position(&mut self, position: u32) {
+    pub fn set_input_y_position(&mut self, position: i32) {
         self.settings.input_y_position = position;
     }
 
-    pub fn get_input_y_position(&self) -> u32 {
+    pub fn get_input_y_position(&self) -> i32 {
         self.settings.input_y_position
     }
 
@@ -276,19 +276,19 @@ impl LockscreenProvider {
         Some(display_text_font)
     }
 
-    pub fn set_display_text_x_position(&mut self, position: u32) {
+    pub fn set_display_text_x_position(&mut self, position: i32) {
         self.settings.display_text_x_position = position;
     }
 
-    pub fn get_display_text_x_position(&self) -> u32 {
+    pub fn get_display_text_x_position(&self) -> i32 {
         self.settings.display_text_x_position
     }
 
-    pub fn set_display_text_y_position(&mut self, position: u32) {
+    pub fn set_display_text_y_position(&mut self, position: i32) {
         self.settings.display_text_y_position = position;
     }
 
-    pub fn get_display_text_y_position(&self) -> u32 {
+    pub fn get_display_text_y_position(&self) -> i32 {
         self.settings.display_text_y_position
     }
 
@@ -321,6 +321,10 @@ impl LockscreenProvider {
         Some(display_text_horizontal_alignment)
     }
     
+    pub fn set_settings(&mut self, settings: LockScreenSettings) {
+        self.settings = settings;
+    }
+
     pub fn get_settings(&self) -> LockScreenSettings {
         self.settings.clone()   
     }
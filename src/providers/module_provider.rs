@@ -148,18 +148,31 @@ impl ModuleProvider {
         program_module_names
     }
 
-    pub fn add_startup_program(&mut self, name: String, path: String) {
-        self.settings.startup_programs.insert(name, path);
+    pub fn set_startup_program(&mut self, previous_name: String, name: String, path: String) {
+        let startup_programs = &mut self.settings.startup_programs;
+        startup_programs.retain(|(program_name, _)| *program_name != name || *program_name == previous_name);
+
+        let startup_program = startup_programs.iter_mut()
+            .find(|(program_name, _)| *program_name == previous_name);
+
+        match startup_program {
+            Some(startup_program) => *startup_program = (name, path),
+            None => startup_programs.push((name, path)),
+        }
     }
 
     pub fn remove_startup_program(&mut self, name: String) {
-        self.settings.startup_programs.remove(&name);
+        self.settings.startup_programs.retain(|(program_name, _)| *program_name != name);
     }
 
-    pub fn get_startup_programs(&self) -> HashMap<String, String> {
+    pub fn get_startup_programs(&self) -> Vec<(String, String)> {
         self.settings.startup_programs.clone()
     }
 
+    pub fn set_settings(&mut self, settings: ProgramSettings) {
+        self.settings = settings;
+    }
+
     pub fn get_settings(&self) -> ProgramSettings {
         self.settings.clone()
     }
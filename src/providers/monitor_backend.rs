@@ -0,0 +1,77 @@
+// A monitor backend asks an external tool for the connected monitors. Reading and parsing the output are separate
+// steps, so the parsers can be fed with captured output of the tools.
+
+use std::env;
+use std::process::Command;
+use crate::models::monitor::monitor_configuration::MonitorConfiguration;
+use crate::persistence::settings_error::SettingsError;
+
+pub trait MonitorBackend {
+    fn get_command_name(&self) -> &'static str;
+    fn get_command_arguments(&self) -> Vec<&'static str>;
+    fn parse_monitor_output(&self, output: &str) -> Result<Vec<MonitorConfiguration>, SettingsError>;
+
+    fn is_available(&self) -> bool {
+        self.is_command_available()
+    }
+
+    fn is_command_available(&self) -> bool {
+        let Some(paths) = env::var_os("PATH") else {
+            return false;
+        };
+
+        env::split_paths(&paths).any(|path| path.join(self.get_command_name()).is_file())
+    }
+
+    fn read_monitor_output(&self) -> Option<String> {
+        let command_output = Command::new(self.get_command_name())
+            .args(self.get_command_arguments())
+            .output()
+            .ok()
+            .filter(|command_output| command_output.status.success())?;
+
+        String::from_utf8(command_output.stdout).ok()
+    }
+
+    fn detect_monitors(&self) -> Result<Vec<MonitorConfiguration>, SettingsError> {
+        match self.read_monitor_output() {
+            Some(output) => self.parse_monitor_output(&output),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+// Both fixtures describe the same monitors, so every backend has to read them the same way
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn find_configuration<'a>(
+        configurations: &'a [MonitorConfiguration], port_name: &str
+    ) -> &'a MonitorConfiguration {
+        configurations.iter()
+            .find(|configuration| configuration.information.port_name == port_name)
+            .unwrap_or_else(|| panic!("{} is not detected", port_name))
+    }
+
+    pub fn assert_fixture_monitors(configurations: &[MonitorConfiguration]) {
+        let configuration = find_configuration(configurations, "DP-1");
+        assert_eq!(configuration.information.brand_name, "Dell Inc.");
+        assert_eq!(configuration.information.model_name, "DELL U2719D");
+        assert_eq!(configuration.information.serial_number, "8H2QX23");
+        assert_eq!(configuration.information.description, "Dell Inc. DELL U2719D 8H2QX23");
+        assert_eq!(configuration.information.video_modes.len(), 5);
+
+        let configuration = find_configuration(configurations, "HDMI-A-1");
+        assert!(configuration.enabled);
+        assert_eq!(configuration.video_mode.to_string(), "3840x2160@60Hz");
+        assert_eq!((configuration.offset.get_x(), configuration.offset.get_y()), (2048.0, -200.0));
+        assert_eq!(configuration.resolution_scale, 1.5);
+        assert_eq!(configuration.orientation.get_hyprland_orientation_code(), 1);
+
+        let configuration = find_configuration(configurations, "eDP-1");
+        assert!(!configuration.enabled);
+        assert_eq!(configuration.video_mode.to_string(), "1920x1200@60Hz");
+        assert_eq!(configuration.resolution_scale, 1.0);
+    }
+}
\ No newline at end of file
@@ -1,77 +1,261 @@
-use std::collections::HashMap;
-use std::process::Command;
+use std::collections::{BTreeMap, BTreeSet};
 use crate::models::monitor::monitor_configuration::{MonitorConfiguration, MonitorOrientation};
-use crate::models::monitor::monitor_info_parser::MonitorInfoParser;
+use crate::models::monitor::monitor_information::MonitorInformation;
+use crate::models::monitor::monitor_layout_profile::MonitorLayoutProfile;
+use crate::models::monitor::video_mode::VideoModePreset;
 use crate::models::settings::monitor_settings::MonitorSettings;
 use crate::math::vector::Vector;
+use crate::providers::hyprctl_monitor_backend::HyprctlMonitorBackend;
+use crate::providers::monitor_backend::MonitorBackend;
+use crate::providers::wlr_randr_monitor_backend::WlrRandrMonitorBackend;
 
 pub struct MonitorProvider {
-    settings: MonitorSettings
+    settings: MonitorSettings,
+    detected_monitors: Option<BTreeMap<String, MonitorInformation>>,
 }
 
 impl MonitorProvider {
     pub fn new(settings: MonitorSettings) -> Self {
         Self {
-            settings
+            settings,
+            detected_monitors: None,
         }
     }
 
     pub fn fetch_monitors(&mut self) {
-        let command_result = Command::new("wlr-randr")
-            .output()
-            .expect("Error during wlrandr execution");
-
-        let output = String::from_utf8(command_result.stdout)
-            .expect("Failed to parse wlr-randr output");
-
-        let mut monitor_info_parser = MonitorInfoParser::new();
-        monitor_info_parser.parse_output(&output);
-        let monitor_information = monitor_info_parser.get_result();
-
-        let monitor_orientation = MonitorOrientation::None;
-        let monitor_configurations: HashMap<String, MonitorConfiguration> = monitor_information
-            .iter()
-            .map(|monitor_information| {
-
-                let port = monitor_information.port_name.clone();
-                let configuration = MonitorConfiguration {
-                    enabled: true,
-                    information: monitor_information.clone(),
-                    video_mode: monitor_information.max_video_mode.clone(),
-                    offset: Vector::new(0.0, 0.0),
-                    resolution_scale: 1.0,
-                    orientation: monitor_orientation.clone(),
-                };
-
-                (port, configuration)
+        self.settings.monitor_configurations = Self::detect_monitor_configurations()
+            .into_iter()
+            .map(|configuration| (configuration.information.port_name.clone(), configuration))
+            .collect();
+    }
+
+    pub fn fetch_monitor_informations(&mut self) {
+        for detected_configuration in Self::detect_monitor_configurations() {
+            let information = detected_configuration.information;
+            if let Some(configuration) = self.settings.monitor_configurations.get_mut(&information.port_name) {
+                configuration.information = information;
+            }
+        }
+    }
+
+    // Saved settings of known monitors are kept and only their information is refreshed, while new monitors are added
+    // with their detected settings. Monitors that are no longer detected stay configured, so they keep their settings
+    // when they are plugged in again.
+    pub fn rescan_monitors(&mut self) -> bool {
+        let detected_configurations = Self::detect_monitor_configurations();
+        if detected_configurations.is_empty() {
+            return false;
+        }
+
+        let mut detected_monitors = BTreeMap::new();
+        for detected_configuration in detected_configurations {
+            let monitor_port = detected_configuration.information.port_name.clone();
+            detected_monitors.insert(monitor_port.clone(), detected_configuration.information.clone());
+
+            match self.settings.monitor_configurations.get_mut(&monitor_port) {
+                Some(configuration) => configuration.information = detected_configuration.information,
+                None => {
+                    let configuration = self.place_new_monitor(detected_configuration);
+                    self.settings.monitor_configurations.insert(monitor_port, configuration);
+                }
+            }
+        }
+
+        self.detected_monitors = Some(detected_monitors);
+        true
+    }
+
+    // The detected monitors report what is currently applied, which can differ from the edited configurations. Video
+    // mode presets are kept as long as they still resolve to the applied video mode.
+    pub fn get_live_monitor_configurations(&self) -> BTreeMap<String, MonitorConfiguration> {
+        let mut monitor_configurations = self.settings.monitor_configurations.clone();
+        for detected_configuration in Self::detect_monitor_configurations() {
+            let monitor_port = detected_configuration.information.port_name.clone();
+            let live_configuration = match monitor_configurations.get(&monitor_port) {
+                Some(configuration) => Self::create_live_configuration(configuration, detected_configuration),
+                None => detected_configuration,
+            };
+
+            monitor_configurations.insert(monitor_port, live_configuration);
+        }
+
+        monitor_configurations
+    }
+
+    fn create_live_configuration(
+        configuration: &MonitorConfiguration, detected_configuration: MonitorConfiguration
+    ) -> MonitorConfiguration {
+        let mut live_configuration = configuration.clone();
+        live_configuration.enabled = detected_configuration.enabled;
+        live_configuration.information = detected_configuration.information;
+        if !detected_configuration.enabled {
+            return live_configuration;
+        }
+
+        let is_preset_video_mode = live_configuration.information
+            .get_video_mode_by_preset(live_configuration.video_mode_preset)
+            .is_some_and(|video_mode| video_mode.is_same_mode(&detected_configuration.video_mode));
+        if !is_preset_video_mode {
+            live_configuration.video_mode_preset = VideoModePreset::Custom;
+        }
+
+        live_configuration.video_mode = detected_configuration.video_mode;
+        live_configuration.offset = detected_configuration.offset;
+        live_configuration.resolution_scale = detected_configuration.resolution_scale;
+        live_configuration.orientation = detected_configuration.orientation;
+        live_configuration
+    }
+
+    pub fn get_missing_monitor_ports(&self) -> BTreeSet<String> {
+        let Some(detected_monitors) = &self.detected_monitors else {
+            return BTreeSet::new();
+        };
+
+        self.settings.monitor_configurations.keys()
+            .filter(|monitor_port| !detected_monitors.contains_key(*monitor_port))
+            .cloned()
+            .collect()
+    }
+
+    // The configurations of the matching layout profile are mapped to the ports the monitors are currently connected
+    // to and merged into the current ones, so disconnected monitors stay configured like after a rescan
+    pub fn apply_matching_layout_profile(&mut self) -> bool {
+        let Some(layout_profile) = self.find_matching_layout_profile().cloned() else {
+            return false;
+        };
+        let Some(detected_monitors) = &self.detected_monitors else {
+            return false;
+        };
+
+        for (monitor_port, information) in detected_monitors {
+            if let Some(configuration) = layout_profile.monitor_configurations.get(&information.get_identity()) {
+                let mut configuration = configuration.clone();
+                configuration.information = information.clone();
+                self.settings.monitor_configurations.insert(monitor_port.clone(), configuration);
+            }
+        }
+
+        true
+    }
+
+    // Stores the configurations of the connected monitors in their layout profile, which is created when this set of
+    // monitors has no profile yet
+    pub fn store_layout_profile(&mut self) {
+        if self.detected_monitors.is_none() {
+            self.detected_monitors = Self::detect_monitor_informations();
+        }
+        let Some(detected_monitors) = &self.detected_monitors else {
+            return;
+        };
+
+        let monitor_configurations: BTreeMap<String, MonitorConfiguration> = detected_monitors.iter()
+            .filter_map(|(monitor_port, information)| {
+                let mut configuration = self.settings.monitor_configurations.get(monitor_port)?.clone();
+                configuration.information = information.clone();
+                Some((information.get_identity(), configuration))
             })
             .collect();
 
-        self.settings.monitor_configurations = monitor_configurations;
+        // Connected monitors without a configuration or with the same identity cannot be stored in a profile
+        if monitor_configurations.len() != detected_monitors.len() {
+            return;
+        }
+
+        match self.find_matching_layout_profile_index() {
+            Some(profile_index) => self.settings.layout_profiles[profile_index].monitor_configurations = monitor_configurations,
+            None => self.settings.layout_profiles.push(MonitorLayoutProfile::new(monitor_configurations)),
+        }
     }
 
-    pub fn set_monitor_state(&mut self, monitor_port: String, state: bool) {
-        let configuration = self.settings.monitor_configurations
-            .get_mut(&monitor_port)
-            .unwrap();
+    pub fn get_matching_layout_profile_name(&self) -> Option<String> {
+        self.find_matching_layout_profile()
+            .map(|layout_profile| layout_profile.name.clone())
+    }
 
-        configuration.enabled = state;
+    fn find_matching_layout_profile(&self) -> Option<&MonitorLayoutProfile> {
+        self.find_matching_layout_profile_index()
+            .map(|profile_index| &self.settings.layout_profiles[profile_index])
+    }
+
+    fn find_matching_layout_profile_index(&self) -> Option<usize> {
+        let monitor_identities = self.get_detected_monitor_identities()?;
+        self.settings.layout_profiles.iter()
+            .position(|layout_profile| layout_profile.matches(&monitor_identities))
+    }
+
+    pub fn get_detected_monitor_identities(&self) -> Option<BTreeSet<String>> {
+        let detected_monitors = self.detected_monitors.as_ref()?;
+        Some(detected_monitors.values()
+            .map(|information| information.get_identity())
+            .collect())
+    }
+
+    pub fn has_missing_monitor_informations(&self) -> bool {
+        self.settings.monitor_configurations
+            .values()
+            .any(|configuration| configuration.information.max_video_mode.width_resolution == 0)
+    }
+
+    pub fn has_monitor_backend() -> bool {
+        Self::get_monitor_backends().iter()
+            .any(|monitor_backend| monitor_backend.is_available())
+    }
+
+    fn get_monitor_backends() -> Vec<Box<dyn MonitorBackend>> {
+        vec![Box::new(HyprctlMonitorBackend), Box::new(WlrRandrMonitorBackend)]
+    }
+
+    fn place_new_monitor(&self, mut configuration: MonitorConfiguration) -> MonitorConfiguration {
+        let enabled_configurations = self.settings.monitor_configurations.values()
+            .filter(|other_configuration| other_configuration.enabled);
+
+        let overlaps_enabled_monitor = enabled_configurations.clone()
+            .any(|other_configuration| configuration.overlaps(other_configuration));
+        if !overlaps_enabled_monitor {
+            return configuration;
+        }
+
+        // Detected monitors without a configured position may overlap, e.g. both at 0x0, so new monitors are placed
+        // to the right of the current layout instead
+        let layout_right_edge = enabled_configurations
+            .map(|other_configuration| other_configuration.offset.get_x() + other_configuration.get_logical_size().get_x())
+            .fold(0.0, f64::max);
+        configuration.offset = Vector::new(layout_right_edge, 0.0);
+        configuration
     }
 
-    pub fn set_monitor_width(&mut self, monitor_port: String, width: u32) {
+    fn detect_monitor_informations() -> Option<BTreeMap<String, MonitorInformation>> {
+        let detected_monitors: BTreeMap<String, MonitorInformation> = Self::detect_monitor_configurations()
+            .into_iter()
+            .map(|configuration| (configuration.information.port_name.clone(), configuration.information))
+            .collect();
+
+        if detected_monitors.is_empty() { None } else { Some(detected_monitors) }
+    }
+
+    // A backend whose output cannot be parsed is skipped like one that detects no monitors, so the next one is used
+    fn detect_monitor_configurations() -> Vec<MonitorConfiguration> {
+        Self::get_monitor_backends().iter()
+            .filter(|monitor_backend| monitor_backend.is_available())
+            .filter_map(|monitor_backend| monitor_backend.detect_monitors().ok())
+            .find(|monitor_configurations| !monitor_configurations.is_empty())
+            .unwrap_or_default()
+    }
+
+    pub fn set_monitor_state(&mut self, monitor_port: String, state: bool) {
         let configuration = self.settings.monitor_configurations
             .get_mut(&monitor_port)
             .unwrap();
 
-        configuration.video_mode.width_resolution = width;
+        configuration.enabled = state;
     }
 
-    pub fn set_monitor_height(&mut self, monitor_port: String, height: u32) {
+    pub fn set_monitor_video_mode(&mut self, monitor_port: String, video_mode_option: &str) {
         let configuration = self.settings.monitor_configurations
             .get_mut(&monitor_port)
             .unwrap();
 
-        configuration.video_mode.height_resolution = height;
+        configuration.select_video_mode_option(video_mode_option);
     }
 
     pub fn set_monitor_offset(&mut self, monitor_port: String, offset: Vector) {
@@ -82,14 +266,6 @@ impl MonitorProvider {
         configuration.offset = offset;
     }
     
-    pub fn set_monitor_refresh_rate(&mut self, monitor_port: String, refresh_rate: u32) {
-        let configuration = self.settings.monitor_configurations
-            .get_mut(&monitor_port)
-            .unwrap();
-        
-        configuration.video_mode.refresh_rate = refresh_rate;
-    }
-    
     pub fn set_monitor_scale(&mut self, monitor_port: String, scale: f32) {
         let configuration = self.settings.monitor_configurations
             .get_mut(&monitor_port)
@@ -110,10 +286,18 @@ impl MonitorProvider {
         self.settings.monitor_configurations.get(&monitor_port).cloned()
     }
 
-    pub fn get_monitor_configurations(&self) -> HashMap<String, MonitorConfiguration> {
+    pub fn get_monitor_configurations(&self) -> BTreeMap<String, MonitorConfiguration> {
         self.settings.monitor_configurations.clone()
     }
 
+    pub fn set_monitor_configurations(&mut self, monitor_configurations: BTreeMap<String, MonitorConfiguration>) {
+        self.settings.monitor_configurations = monitor_configurations;
+    }
+
+    pub fn set_settings(&mut self, settings: MonitorSettings) {
+        self.settings = settings;
+    }
+
     pub fn get_settings(&self) -> MonitorSettings {
         self.settings.clone()
     }
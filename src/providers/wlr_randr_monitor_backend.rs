@@ -0,0 +1,73 @@
+use crate::models::monitor::monitor_configuration::MonitorConfiguration;
+use crate::models::monitor::monitor_info_parser::MonitorInfoParser;
+use crate::persistence::settings_error::SettingsError;
+use crate::providers::monitor_backend::MonitorBackend;
+
+pub struct WlrRandrMonitorBackend;
+
+impl MonitorBackend for WlrRandrMonitorBackend {
+    fn get_command_name(&self) -> &'static str {
+        "wlr-randr"
+    }
+
+    fn get_command_arguments(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parse_monitor_output(&self, output: &str) -> Result<Vec<MonitorConfiguration>, SettingsError> {
+        let mut monitor_info_parser = MonitorInfoParser::new();
+        monitor_info_parser.parse_output(output);
+        Ok(monitor_info_parser.get_result().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::providers::monitor_backend::tests::assert_fixture_monitors;
+    use super::*;
+
+    const WLR_RANDR_OUTPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/wlr_randr.txt"));
+
+    fn parse_fixture() -> Vec<MonitorConfiguration> {
+        WlrRandrMonitorBackend.parse_monitor_output(WLR_RANDR_OUTPUT).unwrap()
+    }
+
+    #[test]
+    fn parses_fixture_monitors() {
+        assert_fixture_monitors(&parse_fixture());
+    }
+
+    #[test]
+    fn parses_all_monitors() {
+        let port_names: Vec<String> = parse_fixture().into_iter()
+            .map(|configuration| configuration.information.port_name)
+            .collect();
+
+        assert_eq!(port_names, vec!["DP-1", "HDMI-A-1", "eDP-1", "DP-2"]);
+    }
+
+    #[test]
+    fn current_mode_is_not_the_largest_mode() {
+        let configuration = &parse_fixture()[0];
+
+        assert_eq!(configuration.information.max_video_mode.to_string(), "2560x1440@74.971Hz");
+        assert_eq!(configuration.video_mode.to_string(), "2560x1440@59.951Hz");
+        assert_eq!(configuration.resolution_scale, 1.25);
+    }
+
+    #[test]
+    fn missing_serial_is_kept_as_reported() {
+        let configuration = &parse_fixture()[2];
+
+        assert_eq!(configuration.information.serial_number, "(null)");
+    }
+
+    #[test]
+    fn enabled_monitor_without_current_mode_falls_back_to_its_largest_mode() {
+        let configuration = &parse_fixture()[3];
+
+        assert!(configuration.enabled);
+        assert_eq!(configuration.video_mode.to_string(), "1920x1080@144.001Hz");
+        assert_eq!((configuration.offset.get_x(), configuration.offset.get_y()), (4608.0, 0.0));
+    }
+}
\ No newline at end of file
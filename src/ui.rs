@@ -11,4 +11,6 @@ pub mod pages;
 pub mod boxes;
 mod box_builder;
 pub mod managed_control;
-pub mod css_styler;
\ No newline at end of file
+pub mod css_styler;
+pub mod config_file_watcher;
+pub mod field_highlighter;
\ No newline at end of file
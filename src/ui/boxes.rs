@@ -14,7 +14,7 @@ impl Boxes {
         let mut current_widget = box_container.first_child();
         while let Some(child_widget) = current_widget.clone() {
             box_container.remove(&child_widget);
-            current_widget = box_container.next_sibling();
+            current_widget = box_container.first_child();
         }
     }
     
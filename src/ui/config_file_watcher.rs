@@ -0,0 +1,42 @@
+use gtk::gio::{Cancellable, File, FileMonitor, FileMonitorFlags};
+use gtk::gio::prelude::{FileExt, FileMonitorExt};
+use crate::persistence::config_directory::ConfigDirectory;
+use crate::persistence::config_snapshot::ConfigSnapshot;
+use crate::ui::managed_control::ManagedControl;
+use crate::ui::manager::control_manager::ControlManager;
+use crate::ui::manager::settings_switcher_manager::{SettingsSwitcherEvent, SettingsSwitcherManager};
+
+pub struct ConfigFileWatcher {
+    directory_monitor: Option<FileMonitor>,
+}
+
+impl ManagedControl<SettingsSwitcherManager> for ConfigFileWatcher {
+    fn init_events_by_manager(&self, manager: SettingsSwitcherManager) {
+        let Some(directory_monitor) = &self.directory_monitor else {
+            return;
+        };
+
+        directory_monitor.connect_changed(move |_, file, _, _| {
+            let is_managed_config = file.basename()
+                .and_then(|file_name| file_name.to_str().map(ConfigSnapshot::is_managed_config))
+                .unwrap_or(false);
+
+            if is_managed_config {
+                manager.send_event(SettingsSwitcherEvent::ConfigFilesChanged);
+            }
+        });
+    }
+}
+
+impl ConfigFileWatcher {
+    pub fn new() -> Self {
+        // The whole config directory is watched, because editors and HyprSettings itself replace files by renaming
+        let directory_monitor = File::for_path(ConfigDirectory::get_path())
+            .monitor_directory(FileMonitorFlags::NONE, None::<&Cancellable>)
+            .ok();
+
+        Self {
+            directory_monitor
+        }
+    }
+}
\ No newline at end of file
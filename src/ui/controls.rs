@@ -13,6 +13,11 @@ pub mod navigation;
 pub mod settings_switcher;
 pub mod monitor;
 pub mod monitor_configurator;
+pub mod save_preview_dialog;
+pub mod error_dialog;
+pub mod monitor_try_dialog;
+pub mod stale_banner;
+pub mod profile_selector;
 
 pub trait Control {
     fn get_widget(&self) -> &GTKBox;
@@ -0,0 +1,32 @@
+use gtk::{AlertDialog, Widget, Window};
+use gtk::prelude::{Cast, IsA, WidgetExt};
+use crate::persistence::settings_error::SettingsError;
+
+pub struct ErrorDialog;
+
+impl ErrorDialog {
+    pub fn present(parent_widget: &impl IsA<Widget>, message: &str, settings_errors: &[SettingsError]) {
+        if settings_errors.is_empty() {
+            return;
+        }
+
+        let error_details = settings_errors.iter()
+            .map(|settings_error| settings_error.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Self::present_message(parent_widget, message, &error_details);
+    }
+
+    pub fn present_message(parent_widget: &impl IsA<Widget>, message: &str, detail: &str) {
+        let alert_dialog = AlertDialog::builder()
+            .modal(true)
+            .message(message)
+            .detail(detail)
+            .build();
+
+        let parent_window = parent_widget.root()
+            .and_then(|root| root.downcast::<Window>().ok());
+        alert_dialog.show(parent_window.as_ref());
+    }
+}
\ No newline at end of file
@@ -66,14 +66,20 @@ impl StateSavableControl for CustomKeyBindInputField {
         let keybinds_provider = application_provider.get_keybinds_provider();
         let mut keybinds_provider_mut = keybinds_provider.borrow_mut();
         let mut state_mut = self.state.borrow_mut();
+        let mut previous_bind_type = None;
         if let Some(name) = state_mut.previous_shortcut_name.clone() {
+            previous_bind_type = keybinds_provider_mut.get_custom_keybind(&name)
+                .map(|custom_keybind| custom_keybind.bind_type);
             keybinds_provider_mut.remove_custom_keybind(name)
         }
 
         if let Some(name) = state_mut.shortcut_name.clone() {
             if let Some(command) = state_mut.command.clone() {
                 if let Some(keybind) = state_mut.keybind.clone() {
-                    let custom_keybind = CustomKeybind::new(command, keybind);
+                    let mut custom_keybind = CustomKeybind::new(command, keybind);
+                    if let Some(bind_type) = previous_bind_type {
+                        custom_keybind.set_bind_type(bind_type);
+                    }
                     keybinds_provider_mut.set_custom_keybind(state_mut.shortcut_name.clone(), Some(custom_keybind));
                     state_mut.previous_shortcut_name = Some(name);
                 }
@@ -2,35 +2,29 @@ use gtk::{Align, DropDown, Label, Orientation, Switch};
 use gtk::glib::Propagation;
 use gtk::prelude::{BoxExt, WidgetExt};
 use crate::models::monitor::monitor_configuration::MonitorOrientation;
+use crate::persistence::settings_validator::SettingsValidator;
 use crate::types::{GTKBox, GTKSpinButton};
 use crate::ui::box_builder::BoxBuilder;
 use crate::ui::controls::activable_control::ActivableControl;
 use crate::ui::controls::Control;
 use crate::ui::controls::selection_box::SelectionBox;
 use crate::ui::controls::spin_button::SpinButton;
+use crate::ui::css_styles::CSSStyles;
+use crate::ui::field_highlighter::FieldHighlighter;
 use crate::ui::labeled_control::LabeledControl;
 use crate::ui::states::monitor_field_state::MonitorFieldState;
 use crate::ui::states::selection_box_state::SelectionBoxState;
 use crate::ui::states::spin_button_state::SpinButtonState;
 use crate::ui::updatable_control::UpdatableControl;
 
-const RESOLUTION_INCREMENT: f64 = 1.0;
-const RESOLUTION_PAGE_INCREMENT: f64 = 120.0;
-const RESOLUTION_CLIMB_RATE: f64 = 10.0;
-const REFRESH_INCREMENT: f64 = 1.0;
-const REFRESH_PAGE_INCREMENT: f64 = 10.0;
-const REFRESH_CLIMB_RATE: f64 = 2.0;
-const FLOAT_DIGITS: u32 = 0;
-
 pub struct MonitorField {
     state: MonitorFieldState,
     monitor_field_box: GTKBox,
     monitor_active_switch: Switch,
     monitor_port_label: Label,
     monitor_name_label: Label,
-    width_spin_button: SpinButton,
-    height_spin_button: SpinButton,
-    refresh_rate_spin_button: SpinButton,
+    missing_label: Label,
+    video_mode_selection_box: SelectionBox,
     resolution_scale_spin_button: SpinButton,
     orientation_selection_box: SelectionBox,
 }
@@ -58,52 +52,14 @@ impl UpdatableControl<MonitorFieldState> for MonitorField {
         let monitor_name = monitor_information.model_name.clone() + " - "
             + monitor_information.brand_name.as_str();
         self.monitor_name_label.set_label(monitor_name.as_str());
+        self.missing_label.set_visible(state.is_missing);
 
-        let min_video_mode = state.monitor_configuration.information.min_video_mode.clone();
-        let max_video_mode = state.monitor_configuration.information.max_video_mode.clone();
-        let current_video_mode = state.monitor_configuration.video_mode.clone();
-
-        let width_button_state = SpinButtonState {
-            label_text: "Width:".to_string(),
-            climb_rate: RESOLUTION_CLIMB_RATE,
-            min_value: min_video_mode.width_resolution as f64,
-            max_value: max_video_mode.width_resolution as f64,
-            digit_count: FLOAT_DIGITS,
-            use_integral_numbers: false,
-            current_value: current_video_mode.width_resolution as f64,
-            increment_value: RESOLUTION_INCREMENT,
-            page_size: 0.0,
-            page_increment_value: RESOLUTION_PAGE_INCREMENT
-        };
-        self.width_spin_button.update_state(width_button_state);
-
-        let height_spin_button_state = SpinButtonState {
-            label_text: "Height:".to_string(),
-            climb_rate: RESOLUTION_CLIMB_RATE,
-            min_value: min_video_mode.height_resolution as f64,
-            max_value: max_video_mode.height_resolution as f64,
-            digit_count: FLOAT_DIGITS,
-            use_integral_numbers: false,
-            current_value: current_video_mode.height_resolution as f64,
-            increment_value: RESOLUTION_INCREMENT,
-            page_size: 0.0,
-            page_increment_value: RESOLUTION_PAGE_INCREMENT
-        };
-        self.height_spin_button.update_state(height_spin_button_state);
-
-        let refresh_rate_spin_button_state = SpinButtonState {
-            label_text: "Refresh Rate:".to_string(),
-            climb_rate: REFRESH_CLIMB_RATE,
-            min_value: min_video_mode.refresh_rate as f64,
-            max_value: max_video_mode.refresh_rate as f64,
-            digit_count: FLOAT_DIGITS,
-            use_integral_numbers: false,
-            current_value: current_video_mode.refresh_rate as f64,
-            increment_value: REFRESH_INCREMENT,
-            page_size: 0.0,
-            page_increment_value: REFRESH_PAGE_INCREMENT
+        let video_mode_selection_box_state = SelectionBoxState {
+            label_text: "Mode:".to_string(),
+            selected_option: Some(state.monitor_configuration.get_selected_video_mode_option()),
+            options: state.monitor_configuration.get_video_mode_option_names(),
         };
-        self.refresh_rate_spin_button.update_state(refresh_rate_spin_button_state);
+        self.video_mode_selection_box.update_state(video_mode_selection_box_state);
 
         let resolution_scale_spin_button_state = SpinButtonState {
             label_text: "Scale:".to_string(),
@@ -136,24 +92,19 @@ impl UpdatableControl<MonitorFieldState> for MonitorField {
 
 impl ActivableControl for MonitorField {
     fn enable_control(&self) {
-        self.width_spin_button.enable_control();
-        self.height_spin_button.enable_control();
-        self.refresh_rate_spin_button.enable_control();
+        self.video_mode_selection_box.enable_control();
         self.orientation_selection_box.enable_control();
     }
 
     fn disable_control(&self) {
-        self.width_spin_button.disable_control();
-        self.height_spin_button.disable_control();
-        self.refresh_rate_spin_button.disable_control();
+        self.video_mode_selection_box.disable_control();
         self.orientation_selection_box.disable_control();
     }
 }
 
 impl MonitorField {
     pub fn new() -> Self {
-        const SIZE_BOX_LABEL_WIDTH: u32 = 50;
-        const REFRESH_BOX_LABEL_WIDTH: u32 = 100;
+        const VIDEO_MODE_BOX_LABEL_WIDTH: u32 = 100;
 
         let monitor_field_box = BoxBuilder::new("monitor-field")
             .set_orientation(Orientation::Vertical)
@@ -178,35 +129,30 @@ impl MonitorField {
         let monitor_name = "".to_string();
         let monitor_name_label = Self::create_label(&monitor_name, 180);
 
+        let missing_label = Self::create_label(&"⚠️ Not connected".to_string(), 120);
+        missing_label.add_css_class(CSSStyles::WARNING_LABEL);
+        missing_label.set_tooltip_text(Some(
+            "This monitor was not detected by the last rescan. Its settings are kept for when it is connected again."
+        ));
+        missing_label.set_visible(false);
+
         monitor_info_box.append(&monitor_active_switch);
         monitor_info_box.append(&monitor_port_label);
         monitor_info_box.append(&monitor_name_label);
+        monitor_info_box.append(&missing_label);
 
-        let size_field_box = BoxBuilder::new("size-field")
-            .set_orientation(Orientation::Vertical)
-            .build();
-
-        let width_spin_button = SpinButton::new();
-        width_spin_button.set_text_width(SIZE_BOX_LABEL_WIDTH);
-
-        let height_spin_button = SpinButton::new();
-        height_spin_button.set_text_width(SIZE_BOX_LABEL_WIDTH);
-
-        size_field_box.append(width_spin_button.get_widget());
-        size_field_box.append(height_spin_button.get_widget());
-
-        let refresh_rate_box = BoxBuilder::new("refresh-rate-box")
+        let video_mode_box = BoxBuilder::new("video-mode-box")
             .set_orientation(Orientation::Vertical)
             .build();
 
-        let refresh_rate_spin_button = SpinButton::new();
-        refresh_rate_spin_button.set_text_width(REFRESH_BOX_LABEL_WIDTH);
+        let video_mode_selection_box = SelectionBox::new();
+        video_mode_selection_box.set_text_width(VIDEO_MODE_BOX_LABEL_WIDTH);
 
         let resolution_scale_spin_button = SpinButton::new();
-        resolution_scale_spin_button.set_text_width(REFRESH_BOX_LABEL_WIDTH);
+        resolution_scale_spin_button.set_text_width(VIDEO_MODE_BOX_LABEL_WIDTH);
 
-        refresh_rate_box.append(refresh_rate_spin_button.get_widget());
-        refresh_rate_box.append(resolution_scale_spin_button.get_widget());
+        video_mode_box.append(video_mode_selection_box.get_widget());
+        video_mode_box.append(resolution_scale_spin_button.get_widget());
 
         let rotation_mode_box = BoxBuilder::new("rotation-box")
             .set_orientation(Orientation::Vertical)
@@ -215,8 +161,7 @@ impl MonitorField {
         let orientation_selection_box = SelectionBox::new();
         rotation_mode_box.append(orientation_selection_box.get_widget());
 
-        video_setting_box.append(&size_field_box);
-        video_setting_box.append(&refresh_rate_box);
+        video_setting_box.append(&video_mode_box);
         video_setting_box.append(&rotation_mode_box);
 
         monitor_field_box.append(&monitor_info_box);
@@ -230,9 +175,8 @@ impl MonitorField {
             monitor_active_switch,
             monitor_port_label,
             monitor_name_label,
-            width_spin_button,
-            height_spin_button,
-            refresh_rate_spin_button,
+            missing_label,
+            video_mode_selection_box,
             resolution_scale_spin_button,
             orientation_selection_box
         }
@@ -242,16 +186,8 @@ impl MonitorField {
         self.monitor_active_switch.connect_state_set(value_change);
     }
 
-    pub fn set_width_change(&self, value_change: impl Fn(&GTKSpinButton) + 'static) {
-        self.width_spin_button.set_value_change(value_change);
-    }
-
-    pub fn set_height_change(&self, value_change: impl Fn(&GTKSpinButton) + 'static) {
-        self.height_spin_button.set_value_change(value_change);
-    }
-    
-    pub fn set_refresh_rate_change(&self, value_change: impl Fn(&GTKSpinButton) + 'static) {
-        self.refresh_rate_spin_button.set_value_change(value_change);
+    pub fn set_video_mode_change(&self, value_change: impl Fn(&DropDown) + 'static) {
+        self.video_mode_selection_box.set_selection_change(value_change);
     }
 
     pub fn set_resolution_scale_change(&self, value_change: impl Fn(&GTKSpinButton) + 'static) {
@@ -262,6 +198,17 @@ impl MonitorField {
         self.orientation_selection_box.set_selection_change(value_change);
     }
 
+    pub fn register_fields(&self, field_highlighter: &FieldHighlighter, monitor_port: &str) {
+        let register_monitor_field = |field_name: &str, widget: &GTKBox| {
+            field_highlighter.register_field(&SettingsValidator::get_monitor_field_path(monitor_port, field_name), widget);
+        };
+
+        register_monitor_field("video_mode", self.video_mode_selection_box.get_widget());
+        register_monitor_field("video_mode.refresh_rate", self.video_mode_selection_box.get_widget());
+        register_monitor_field("resolution_scale", self.resolution_scale_spin_button.get_widget());
+        register_monitor_field("offset", &self.monitor_field_box);
+    }
+
     fn create_label(text: &String, width: i32) -> Label {
         let label = Label::new(Some(text.as_str()));
         label.set_width_request(width);
@@ -0,0 +1,110 @@
+use gtk::{Align, Button, Label, Orientation, Window};
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, WidgetExt};
+use crate::types::GTKBox;
+use crate::ui::box_builder::BoxBuilder;
+use crate::ui::boxes::DEFAULT_MARGIN;
+use crate::ui::controls::Control;
+use crate::ui::states::monitor_try_dialog_state::MonitorTryDialogState;
+use crate::ui::updatable_control::UpdatableControl;
+
+pub struct MonitorTryDialog {
+    state: MonitorTryDialogState,
+    dialog_window: Window,
+    dialog_box: GTKBox,
+    countdown_label: Label,
+    keep_button: Button,
+    revert_button: Button,
+}
+
+impl Control for MonitorTryDialog {
+    fn get_widget(&self) -> &GTKBox {
+        &self.dialog_box
+    }
+}
+
+impl UpdatableControl<MonitorTryDialogState> for MonitorTryDialog {
+    fn update_state(&mut self, state: MonitorTryDialogState) {
+        let countdown_text = format!(
+            "The previous monitor settings are restored in {} seconds.", state.remaining_seconds
+        );
+        self.countdown_label.set_label(countdown_text.as_str());
+
+        self.state = state;
+    }
+
+    fn get_current_state(&self) -> MonitorTryDialogState {
+        self.state.clone()
+    }
+}
+
+impl MonitorTryDialog {
+    pub fn new(parent_window: Option<Window>) -> Self {
+        // Without a close button the dialog can only be left by keeping or reverting the monitor settings
+        let dialog_window = Window::builder()
+            .title("Keep monitor settings?")
+            .modal(true)
+            .deletable(false)
+            .resizable(false)
+            .build();
+        dialog_window.set_transient_for(parent_window.as_ref());
+
+        let dialog_box = BoxBuilder::new("monitor-try-dialog")
+            .set_orientation(Orientation::Vertical)
+            .set_margin(DEFAULT_MARGIN)
+            .build();
+
+        let description_label = Label::new(Some("Do you want to keep the new monitor settings?"));
+        description_label.set_xalign(0.0);
+
+        let countdown_label = Label::new(None);
+        countdown_label.set_xalign(0.0);
+
+        let button_box = BoxBuilder::new("monitor-try-buttons")
+            .set_orientation(Orientation::Horizontal)
+            .build();
+        button_box.set_halign(Align::End);
+
+        let revert_button = Button::with_label("Revert");
+        let keep_button = Button::with_label("Keep");
+        keep_button.set_widget_name("save-settings");
+
+        button_box.append(&revert_button);
+        button_box.append(&keep_button);
+
+        dialog_box.append(&description_label);
+        dialog_box.append(&countdown_label);
+        dialog_box.append(&button_box);
+        dialog_window.set_child(Some(&dialog_box));
+
+        let state = Default::default();
+
+        Self {
+            state,
+            dialog_window,
+            dialog_box,
+            countdown_label,
+            keep_button,
+            revert_button,
+        }
+    }
+
+    pub fn set_keep_click(&self, keep_click: impl Fn() + 'static) {
+        self.keep_button.connect_clicked(move |_| {
+            keep_click();
+        });
+    }
+
+    pub fn set_revert_click(&self, revert_click: impl Fn() + 'static) {
+        self.revert_button.connect_clicked(move |_| {
+            revert_click();
+        });
+    }
+
+    pub fn present(&self) {
+        self.dialog_window.present();
+    }
+
+    pub fn close(&self) {
+        self.dialog_window.close();
+    }
+}
\ No newline at end of file
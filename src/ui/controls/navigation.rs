@@ -5,7 +5,7 @@ use crate::ui::box_builder::BoxBuilder;
 use crate::ui::manager::settings_switcher_manager::{SettingsSwitcherEvent, SettingsSwitcherManager};
 use crate::ui::controls::Control;
 use crate::ui::manager::control_manager::ControlManager;
-use crate::ui::pages::{APPEARANCE_PAGE, MONITORS_PAGE, USER_PROGRAMS_PAGE, INFO_PAGE, INPUT_PAGE, KEYBINDS_PAGE, LOCKSCREEN_PAGE, OVERVIEW_PAGE, STARTUP_PROGRAMS_PAGE, WALLPAPER_PAGE};
+use crate::ui::pages::{APPEARANCE_PAGE, BACKUPS_PAGE, MONITORS_PAGE, USER_PROGRAMS_PAGE, INFO_PAGE, INPUT_PAGE, KEYBINDS_PAGE, LOCKSCREEN_PAGE, OVERVIEW_PAGE, STARTUP_PROGRAMS_PAGE, WALLPAPER_PAGE};
 
 pub struct Navigation {
     settings_switcher_manager: SettingsSwitcherManager,
@@ -19,6 +19,7 @@ pub struct Navigation {
     startup_button: Button,
     input_button: Button,   
     keybinds_button: Button,
+    backups_button: Button,
     info_button: Button,
     save_button: Button,
 }
@@ -45,6 +46,7 @@ impl Navigation {
         let input_button = Self::create_settings_button("🖱 input");
         let startup_button = Self::create_settings_button("🚀 startup");
         let keybinds_button = Self::create_settings_button("⌨️ keybinds");
+        let backups_button = Self::create_settings_button("🗂️ backups");
         let info_button = Self::create_settings_button("ℹ️ info");
     
         let save_button = Self::create_settings_button("💾 save");
@@ -60,6 +62,7 @@ impl Navigation {
         settings_navigation_box.append(&input_button);
         settings_navigation_box.append(&startup_button);
         settings_navigation_box.append(&keybinds_button);
+        settings_navigation_box.append(&backups_button);
         settings_navigation_box.append(&info_button);
         settings_navigation_box.append(&save_button);
         
@@ -75,6 +78,7 @@ impl Navigation {
             startup_button,
             input_button,
             keybinds_button,
+            backups_button,
             info_button,
             save_button,
         }
@@ -127,13 +131,18 @@ impl Navigation {
         );
         self.keybinds_button.connect_clicked(keybinds_button_click);
 
+        let backups_button_click = Self::create_settings_button_click(
+            BACKUPS_PAGE.to_string(), settings_switcher_manager.clone()
+        );
+        self.backups_button.connect_clicked(backups_button_click);
+
         let info_button_click = Self::create_settings_button_click(
             INFO_PAGE.to_string(), settings_switcher_manager.clone()
         );
         self.info_button.connect_clicked(info_button_click);
 
         let save_button_click = move |_: &Button| {
-            settings_switcher_manager.send_event(SettingsSwitcherEvent::SaveSettings);
+            settings_switcher_manager.send_event(SettingsSwitcherEvent::ReviewSettings);
         };
         self.save_button.connect_clicked(save_button_click);
     }
@@ -0,0 +1,145 @@
+use gtk::{Button, Entry, Label, Orientation};
+use gtk::prelude::{BoxExt, ButtonExt, EditableExt, EntryExt, WidgetExt};
+use crate::types::GTKBox;
+use crate::ui::box_builder::BoxBuilder;
+use crate::ui::controls::Control;
+use crate::ui::controls::selection_box::SelectionBox;
+use crate::ui::labeled_control::LabeledControl;
+use crate::ui::managed_control::ManagedControl;
+use crate::ui::manager::control_manager::ControlManager;
+use crate::ui::manager::settings_switcher_manager::{SettingsSwitcherEvent, SettingsSwitcherManager};
+use crate::ui::states::profile_selector_state::ProfileSelectorState;
+use crate::ui::states::selection_box_state::SelectionBoxState;
+use crate::ui::updatable_control::UpdatableControl;
+
+const PROFILE_LABEL_WIDTH: u32 = 80;
+
+pub struct ProfileSelector {
+    state: ProfileSelectorState,
+    profile_selector_box: GTKBox,
+    active_profile_label: Label,
+    profile_selection_box: SelectionBox,
+    profile_name_entry: Entry,
+    activate_button: Button,
+    clone_button: Button,
+    rename_button: Button,
+    delete_button: Button,
+}
+
+impl Control for ProfileSelector {
+    fn get_widget(&self) -> &GTKBox {
+        &self.profile_selector_box
+    }
+}
+
+impl UpdatableControl<ProfileSelectorState> for ProfileSelector {
+    fn update_state(&mut self, state: ProfileSelectorState) {
+        let active_profile_text = match &state.active_profile {
+            Some(active_profile) => format!("Active profile: {}", active_profile),
+            None => "No active profile".to_string(),
+        };
+        self.active_profile_label.set_text(active_profile_text.as_str());
+
+        let selection_box_state = SelectionBoxState {
+            label_text: "Profile".to_string(),
+            selected_option: state.active_profile.clone(),
+            options: state.profile_names.clone(),
+        };
+        self.profile_selection_box.update_state(selection_box_state);
+
+        let has_profiles = !state.profile_names.is_empty();
+        self.activate_button.set_sensitive(has_profiles);
+        self.rename_button.set_sensitive(has_profiles);
+        self.delete_button.set_sensitive(has_profiles);
+        self.profile_name_entry.set_text("");
+
+        self.state = state;
+    }
+
+    fn get_current_state(&self) -> ProfileSelectorState {
+        self.state.clone()
+    }
+}
+
+impl ManagedControl<SettingsSwitcherManager> for ProfileSelector {
+    fn init_events_by_manager(&self, manager: SettingsSwitcherManager) {
+        let manager_clone = manager.clone();
+        self.activate_button.connect_clicked(move |_| {
+            manager_clone.send_event(SettingsSwitcherEvent::ActivateProfile);
+        });
+
+        let manager_clone = manager.clone();
+        self.clone_button.connect_clicked(move |_| {
+            manager_clone.send_event(SettingsSwitcherEvent::CloneProfile);
+        });
+
+        let manager_clone = manager.clone();
+        self.rename_button.connect_clicked(move |_| {
+            manager_clone.send_event(SettingsSwitcherEvent::RenameProfile);
+        });
+
+        self.delete_button.connect_clicked(move |_| {
+            manager.send_event(SettingsSwitcherEvent::DeleteProfile);
+        });
+    }
+}
+
+impl ProfileSelector {
+    pub fn new() -> Self {
+        let profile_selector_box = BoxBuilder::new("profile-selector")
+            .set_orientation(Orientation::Vertical)
+            .set_margin_top(10)
+            .build();
+
+        let active_profile_label = Label::new(None);
+        active_profile_label.set_xalign(0.0);
+
+        let profile_selection_box = SelectionBox::new();
+        profile_selection_box.set_text_width(PROFILE_LABEL_WIDTH);
+
+        let profile_name_entry = Entry::new();
+        profile_name_entry.set_placeholder_text(Some("Name for clone or rename"));
+
+        let profile_button_box = BoxBuilder::new("profile-buttons")
+            .set_orientation(Orientation::Horizontal)
+            .build();
+        profile_button_box.set_homogeneous(true);
+
+        let activate_button = Button::with_label("Activate");
+        let clone_button = Button::with_label("Clone");
+        let rename_button = Button::with_label("Rename");
+        let delete_button = Button::with_label("Delete");
+
+        profile_button_box.append(&activate_button);
+        profile_button_box.append(&clone_button);
+        profile_button_box.append(&rename_button);
+        profile_button_box.append(&delete_button);
+
+        profile_selector_box.append(&active_profile_label);
+        profile_selector_box.append(profile_selection_box.get_widget());
+        profile_selector_box.append(&profile_name_entry);
+        profile_selector_box.append(&profile_button_box);
+
+        let state = Default::default();
+
+        Self {
+            state,
+            profile_selector_box,
+            active_profile_label,
+            profile_selection_box,
+            profile_name_entry,
+            activate_button,
+            clone_button,
+            rename_button,
+            delete_button,
+        }
+    }
+
+    pub fn get_selected_profile(&self) -> String {
+        self.profile_selection_box.get_selected_item()
+    }
+
+    pub fn get_profile_name(&self) -> String {
+        self.profile_name_entry.text().to_string()
+    }
+}
\ No newline at end of file
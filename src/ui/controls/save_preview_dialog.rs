@@ -0,0 +1,144 @@
+use gtk::{Align, Button, Label, Notebook, Orientation, ScrolledWindow, TextView, Window};
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, TextBufferExt, TextViewExt, WidgetExt};
+use crate::persistence::config_diff::ConfigDiff;
+use crate::types::GTKBox;
+use crate::ui::box_builder::BoxBuilder;
+use crate::ui::boxes::DEFAULT_MARGIN;
+use crate::ui::controls::Control;
+use crate::ui::css_styles::CSSStyles;
+use crate::ui::states::save_preview_dialog_state::SavePreviewDialogState;
+use crate::ui::updatable_control::UpdatableControl;
+
+const DIFF_CONTEXT_LINE_COUNT: usize = 3;
+
+pub struct SavePreviewDialog {
+    state: SavePreviewDialogState,
+    dialog_window: Window,
+    dialog_box: GTKBox,
+    diff_notebook: Notebook,
+    warnings_label: Label,
+    save_button: Button,
+}
+
+impl Control for SavePreviewDialog {
+    fn get_widget(&self) -> &GTKBox {
+        &self.dialog_box
+    }
+}
+
+impl UpdatableControl<SavePreviewDialogState> for SavePreviewDialog {
+    fn update_state(&mut self, state: SavePreviewDialogState) {
+        while self.diff_notebook.n_pages() > 0 {
+            self.diff_notebook.remove_page(Some(0));
+        }
+
+        for (config_name, diff_lines) in &state.changed_configs {
+            let diff_text_view = TextView::new();
+            diff_text_view.set_editable(false);
+            diff_text_view.set_cursor_visible(false);
+            diff_text_view.set_monospace(true);
+            diff_text_view.buffer().set_text(
+                ConfigDiff::create_diff_text(diff_lines, DIFF_CONTEXT_LINE_COUNT).as_str()
+            );
+
+            let diff_scroll_window = ScrolledWindow::new();
+            diff_scroll_window.set_vexpand(true);
+            diff_scroll_window.set_child(Some(&diff_text_view));
+
+            let config_name_label = Label::new(Some(config_name));
+            self.diff_notebook.append_page(&diff_scroll_window, Some(&config_name_label));
+        }
+
+        if state.changed_configs.is_empty() {
+            let no_changes_label = Label::new(Some("The config files on disk already match the current settings."));
+            no_changes_label.set_margin_top(DEFAULT_MARGIN as i32);
+            self.diff_notebook.append_page(&no_changes_label, Some(&Label::new(Some("No changes"))));
+        }
+
+        self.warnings_label.set_text(state.warnings.join("\n").as_str());
+        self.warnings_label.set_visible(!state.warnings.is_empty());
+
+        self.state = state;
+    }
+
+    fn get_current_state(&self) -> SavePreviewDialogState {
+        self.state.clone()
+    }
+}
+
+impl SavePreviewDialog {
+    pub fn new(parent_window: Option<Window>) -> Self {
+        let dialog_window = Window::builder()
+            .title("Review changes")
+            .modal(true)
+            .default_width(900)
+            .default_height(640)
+            .build();
+        dialog_window.set_transient_for(parent_window.as_ref());
+
+        let dialog_box = BoxBuilder::new("save-preview-dialog")
+            .set_orientation(Orientation::Vertical)
+            .set_margin(DEFAULT_MARGIN)
+            .build();
+
+        let description_label = Label::new(Some(
+            "Lines marked with + are added and lines marked with - are removed from the config files on save."
+        ));
+        description_label.set_xalign(0.0);
+
+        let warnings_label = Label::new(None);
+        warnings_label.set_xalign(0.0);
+        warnings_label.set_wrap(true);
+        warnings_label.add_css_class(CSSStyles::WARNING_LABEL);
+        warnings_label.set_visible(false);
+
+        let diff_notebook = Notebook::new();
+        diff_notebook.set_vexpand(true);
+
+        let button_box = BoxBuilder::new("save-preview-buttons")
+            .set_orientation(Orientation::Horizontal)
+            .build();
+        button_box.set_halign(Align::End);
+
+        let cancel_button = Button::with_label("Cancel");
+        let dialog_window_clone = dialog_window.clone();
+        cancel_button.connect_clicked(move |_| {
+            dialog_window_clone.close();
+        });
+
+        let save_button = Button::with_label("Save");
+        save_button.set_widget_name("save-settings");
+
+        button_box.append(&cancel_button);
+        button_box.append(&save_button);
+
+        dialog_box.append(&description_label);
+        dialog_box.append(&warnings_label);
+        dialog_box.append(&diff_notebook);
+        dialog_box.append(&button_box);
+        dialog_window.set_child(Some(&dialog_box));
+
+        let state = Default::default();
+
+        Self {
+            state,
+            dialog_window,
+            dialog_box,
+            diff_notebook,
+            warnings_label,
+            save_button,
+        }
+    }
+
+    pub fn set_save_click(&self, save_click: impl Fn() + 'static) {
+        let dialog_window = self.dialog_window.clone();
+        self.save_button.connect_clicked(move |_| {
+            save_click();
+            dialog_window.close();
+        });
+    }
+
+    pub fn present(&self) {
+        self.dialog_window.present();
+    }
+}
\ No newline at end of file
@@ -105,6 +105,10 @@ impl SelectionBox {
         }
     }
 
+    pub fn get_selected_item(&self) -> String {
+        Self::get_selected_option(&self.selection_dropdown)
+    }
+
     pub fn set_selection_change(&self, selection_change: impl Fn(&DropDown) + 'static) {
         self.selection_dropdown.connect_selected_notify(selection_change);
     }
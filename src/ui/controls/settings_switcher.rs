@@ -60,6 +60,12 @@ impl SettingsSwitcher {
         self
     }
 
+    pub fn remove_controls(&mut self) {
+        for (_, control) in self.controls.drain() {
+            self.settings_switcher_stack.remove(control.borrow().get_widget());
+        }
+    }
+
     pub fn set_control_visible(&self, control_name: &str) {
         let control_box = self.settings_switcher_stack.child_by_name(control_name);
         if let Some(control_box) = control_box {
@@ -0,0 +1,92 @@
+use gtk::{Align, Button, Label, Orientation};
+use gtk::prelude::{BoxExt, ButtonExt, WidgetExt};
+use crate::types::GTKBox;
+use crate::ui::box_builder::BoxBuilder;
+use crate::ui::boxes::DEFAULT_MARGIN;
+use crate::ui::controls::Control;
+use crate::ui::css_styles::CSSStyles;
+use crate::ui::managed_control::ManagedControl;
+use crate::ui::manager::control_manager::ControlManager;
+use crate::ui::manager::settings_switcher_manager::{SettingsSwitcherEvent, SettingsSwitcherManager};
+use crate::ui::states::stale_banner_state::StaleBannerState;
+use crate::ui::updatable_control::UpdatableControl;
+
+pub struct StaleBanner {
+    state: StaleBannerState,
+    stale_banner_box: GTKBox,
+    changed_configs_label: Label,
+    reload_button: Button,
+    keep_button: Button,
+}
+
+impl Control for StaleBanner {
+    fn get_widget(&self) -> &GTKBox {
+        &self.stale_banner_box
+    }
+}
+
+impl UpdatableControl<StaleBannerState> for StaleBanner {
+    fn update_state(&mut self, state: StaleBannerState) {
+        let changed_configs_text = format!(
+            "⚠️ Changed outside of HyprSettings: {}. Saving now overwrites these changes.",
+            state.changed_configs.join(", ")
+        );
+        self.changed_configs_label.set_label(changed_configs_text.as_str());
+        self.stale_banner_box.set_visible(!state.changed_configs.is_empty());
+
+        self.state = state;
+    }
+
+    fn get_current_state(&self) -> StaleBannerState {
+        self.state.clone()
+    }
+}
+
+impl ManagedControl<SettingsSwitcherManager> for StaleBanner {
+    fn init_events_by_manager(&self, manager: SettingsSwitcherManager) {
+        let manager_clone = manager.clone();
+        self.reload_button.connect_clicked(move |_| {
+            manager_clone.send_event(SettingsSwitcherEvent::ReloadSettings);
+        });
+
+        self.keep_button.connect_clicked(move |_| {
+            manager.send_event(SettingsSwitcherEvent::KeepSessionSettings);
+        });
+    }
+}
+
+impl StaleBanner {
+    pub fn new() -> Self {
+        let stale_banner_box = BoxBuilder::new("stale-banner")
+            .set_orientation(Orientation::Horizontal)
+            .build();
+        stale_banner_box.set_margin_bottom(DEFAULT_MARGIN as i32);
+        stale_banner_box.set_visible(false);
+
+        let changed_configs_label = Label::new(None);
+        changed_configs_label.add_css_class(CSSStyles::WARNING_LABEL);
+        changed_configs_label.set_hexpand(true);
+        changed_configs_label.set_xalign(0.0);
+        changed_configs_label.set_wrap(true);
+
+        let reload_button = Button::with_label("Reload from disk");
+        reload_button.set_valign(Align::Center);
+
+        let keep_button = Button::with_label("Keep my changes");
+        keep_button.set_valign(Align::Center);
+
+        stale_banner_box.append(&changed_configs_label);
+        stale_banner_box.append(&reload_button);
+        stale_banner_box.append(&keep_button);
+
+        let state = Default::default();
+
+        Self {
+            state,
+            stale_banner_box,
+            changed_configs_label,
+            reload_button,
+            keep_button,
+        }
+    }
+}
\ No newline at end of file
@@ -69,8 +69,9 @@ impl StateSavableControl for StartupProgramField {
         program_provider_mut.remove_program(previous_program_name.clone());
         program_provider_mut.add_program(program_name.clone(), program_path.clone());
 
-        program_provider_mut.remove_startup_program(previous_program_name.clone());
-        program_provider_mut.add_startup_program(program_name.clone(), program_path.clone());
+        program_provider_mut.set_startup_program(
+            previous_program_name.clone(), program_name.clone(), program_path.clone()
+        );
         state_mut.previous_program_name = program_name.clone();
     }
 
@@ -1,3 +1,5 @@
+use std::env;
+use std::path::{Path, PathBuf};
 use gtk::{CssProvider, InterfaceColorScheme, Settings, STYLE_PROVIDER_PRIORITY_APPLICATION};
 use gtk::gdk::Display;
 use crate::ui::managed_control::ManagedControl;
@@ -26,6 +28,9 @@ impl ManagedControl<CSSStylerManager> for CSSStyler {
 impl CSSStyler{
     const LIGHT_STYLE_FILE: &'static str = "light-style.css";
     const DARK_STYLE_FILE: &'static str = "dark-style.css";
+    const APPLICATION_DIRECTORY: &'static str = "hyprsettings";
+    const DEFAULT_DATA_DIRECTORY: &'static str = "/usr/share";
+    const DEFAULT_DATA_DIRECTORIES: &'static str = "/usr/local/share:/usr/share";
 
     pub fn new() -> Self {
         let settings = new_rc_mut(Settings::default().expect("Could not get default GTK settings"));
@@ -35,13 +40,33 @@ impl CSSStyler{
     }
 
     fn get_full_style_path(style_file: &str) -> String {
-        let shared_application_path = std::path::Path::new("/usr/share/hyprsettings");
-        let style_path = shared_application_path.join(style_file);
-        if let Some(style_path) = style_path.to_str() {
-            style_path.to_string()
-        } else {
-            "".to_string()
+        let style_path = Self::get_data_directories().iter()
+            .map(|data_directory| data_directory.join(Self::APPLICATION_DIRECTORY).join(style_file))
+            .find(|style_path| style_path.is_file())
+            .unwrap_or(Path::new(Self::DEFAULT_DATA_DIRECTORY).join(Self::APPLICATION_DIRECTORY).join(style_file));
+
+        style_path.to_string_lossy().to_string()
+    }
+
+    fn get_data_directories() -> Vec<PathBuf> {
+        let mut data_directories = Vec::new();
+        if let Ok(data_home) = env::var("XDG_DATA_HOME") && !data_home.is_empty() {
+            data_directories.push(PathBuf::from(data_home));
+        } else if let Ok(home_path) = env::var("HOME") {
+            data_directories.push(Path::new(&home_path).join(".local/share"));
         }
+
+        let data_directories_value = env::var("XDG_DATA_DIRS")
+            .ok()
+            .filter(|data_directories_value| !data_directories_value.is_empty())
+            .unwrap_or(Self::DEFAULT_DATA_DIRECTORIES.to_string());
+
+        data_directories.extend(
+            data_directories_value.split(':')
+                .filter(|data_directory| !data_directory.is_empty())
+                .map(PathBuf::from)
+        );
+        data_directories
     }
 
     pub fn apply_current_style_settings(&self) {
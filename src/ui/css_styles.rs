@@ -11,4 +11,6 @@ impl CSSStyles {
     pub const KEY_SYMBOL_LABEL: &'static str = "key-symbol";
     pub const KEY_SYMBOL_BOX: &'static str = "key-symbol";
     pub const MONITOR_BOX: &'static str = "monitor";
+    pub const INVALID_FIELD: &'static str = "invalid-field";
+    pub const SUSPICIOUS_FIELD: &'static str = "suspicious-field";
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+use std::collections::BTreeMap;
+use gtk::Widget;
+use gtk::prelude::{Cast, IsA, WidgetExt};
+use crate::persistence::settings_diagnostic::SettingsDiagnostic;
+use crate::ui::css_styles::CSSStyles;
+use crate::utils::{new_rc_mut, RcMut};
+
+#[derive(Clone)]
+pub struct FieldHighlighter {
+    field_widgets: RcMut<BTreeMap<String, Widget>>,
+}
+
+impl FieldHighlighter {
+    pub fn new() -> Self {
+        Self {
+            field_widgets: new_rc_mut(BTreeMap::new())
+        }
+    }
+
+    pub fn register_field(&self, field_path: &str, widget: &impl IsA<Widget>) {
+        self.field_widgets.borrow_mut().insert(field_path.to_string(), widget.clone().upcast());
+    }
+
+    pub fn clear_fields(&self) {
+        self.field_widgets.borrow_mut().clear();
+    }
+
+    pub fn highlight_diagnostics(&self, diagnostics: &[SettingsDiagnostic]) {
+        let field_widgets = self.field_widgets.borrow();
+        for widget in field_widgets.values() {
+            widget.remove_css_class(CSSStyles::INVALID_FIELD);
+            widget.remove_css_class(CSSStyles::SUSPICIOUS_FIELD);
+            widget.set_tooltip_text(None);
+        }
+
+        // Several fields can share one widget, so their messages are appended to the tooltip
+        for (field_path, widget) in field_widgets.iter() {
+            let field_diagnostics: Vec<&SettingsDiagnostic> = diagnostics.iter()
+                .filter(|diagnostic| &diagnostic.field_path == field_path)
+                .collect();
+
+            if field_diagnostics.is_empty() {
+                continue;
+            }
+
+            if field_diagnostics.iter().any(|diagnostic| diagnostic.is_error()) {
+                widget.add_css_class(CSSStyles::INVALID_FIELD);
+            } else {
+                widget.add_css_class(CSSStyles::SUSPICIOUS_FIELD);
+            }
+
+            let mut diagnostic_messages: Vec<String> = widget.tooltip_text()
+                .map(|tooltip_text| vec![tooltip_text.to_string()])
+                .unwrap_or_default();
+            diagnostic_messages.extend(field_diagnostics.iter().map(|diagnostic| diagnostic.message.clone()));
+            widget.set_tooltip_text(Some(diagnostic_messages.join("\n").as_str()));
+        }
+    }
+}
\ No newline at end of file
@@ -6,4 +6,7 @@ pub mod monitor_configurator_manager;
 pub mod control_manager;
 pub mod editable_control_manager;
 pub mod input_manager;
-pub mod css_styler_manager;
\ No newline at end of file
+pub mod css_styler_manager;
+pub mod lockscreen_manager;
+pub mod backups_manager;
+pub mod monitors_manager;
\ No newline at end of file
@@ -0,0 +1,57 @@
+use crate::persistence::config_backup::ConfigBackup;
+use crate::providers::application_provider::ApplicationProvider;
+use crate::ui::controls::Control;
+use crate::ui::controls::error_dialog::ErrorDialog;
+use crate::ui::manager::control_manager::ControlManager;
+use crate::ui::manager::settings_switcher_manager::{SettingsSwitcherEvent, SettingsSwitcherManager};
+use crate::ui::pages::backups::Backups;
+use crate::ui::states::backups_page_state::BackupsPageState;
+use crate::ui::updatable_control::UpdatableControl;
+use crate::utils::RcMut;
+
+#[derive(Clone)]
+pub struct BackupsManager {
+    backups: RcMut<Backups>,
+    settings_switcher_manager: SettingsSwitcherManager,
+    application_provider: ApplicationProvider,
+}
+
+pub enum BackupsManagerEvent {
+    RefreshBackups,
+    RestoreSelectedBackup,
+}
+
+impl BackupsManager {
+    pub fn new(
+        backups: RcMut<Backups>,
+        settings_switcher_manager: SettingsSwitcherManager,
+        application_provider: ApplicationProvider
+    ) -> Self {
+        Self {
+            backups,
+            settings_switcher_manager,
+            application_provider
+        }
+    }
+
+    pub fn send_event(&self, event: BackupsManagerEvent) {
+        match event {
+            BackupsManagerEvent::RefreshBackups => {
+                let state = BackupsPageState::from(&self.application_provider);
+                self.backups.borrow_mut().update_state(state);
+            },
+            BackupsManagerEvent::RestoreSelectedBackup => {
+                let selected_backup = self.backups.borrow().get_selected_backup();
+                if selected_backup.is_empty() {
+                    return;
+                }
+
+                if let Err(error) = ConfigBackup::restore_backup(&selected_backup) {
+                    ErrorDialog::present(self.backups.borrow().get_widget(), "Cannot restore the save point.", &[error]);
+                }
+
+                self.settings_switcher_manager.send_event(SettingsSwitcherEvent::ReloadSettings);
+            }
+        }
+    }
+}
\ No newline at end of file
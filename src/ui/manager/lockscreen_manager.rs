@@ -0,0 +1,46 @@
+use crate::providers::application_provider::ApplicationProvider;
+use crate::ui::controls::Control;
+use crate::ui::controls::error_dialog::ErrorDialog;
+use crate::ui::manager::lockscreen_manager::LockscreenManagerEvent::ImportHyprlockConfig;
+use crate::ui::pages::lockscreen::Lockscreen;
+use crate::ui::states::lockscreen_page_state::LockScreenPageState;
+use crate::ui::updatable_control::UpdatableControl;
+use crate::utils::RcMut;
+
+#[derive(Clone)]
+pub struct LockscreenManager {
+    lockscreen: RcMut<Lockscreen>,
+    application_provider: ApplicationProvider,
+}
+
+pub enum LockscreenManagerEvent {
+    ImportHyprlockConfig,
+}
+
+impl LockscreenManager {
+    pub fn new(lockscreen: RcMut<Lockscreen>, application_provider: ApplicationProvider) -> Self {
+        Self {
+            lockscreen,
+            application_provider
+        }
+    }
+
+    pub fn send_event(&self, event: LockscreenManagerEvent) {
+        match event {
+            ImportHyprlockConfig => {
+                let lockscreen_provider = self.application_provider.get_lockscreen_provider();
+                let current_settings = lockscreen_provider.borrow().get_settings();
+                match ApplicationProvider::import_lockscreen_settings(current_settings) {
+                    Ok(imported_settings) => lockscreen_provider.borrow_mut().set_settings(imported_settings),
+                    Err(error) => {
+                        ErrorDialog::present(self.lockscreen.borrow().get_widget(), "Cannot import hyprlock.conf.", &[error]);
+                        return;
+                    }
+                }
+
+                let state = LockScreenPageState::from(&self.application_provider);
+                self.lockscreen.borrow_mut().update_state(state);
+            }
+        }
+    }
+}
\ No newline at end of file
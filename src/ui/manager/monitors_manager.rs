@@ -0,0 +1,214 @@
+use std::collections::{BTreeMap, BTreeSet};
+use gtk::Window;
+use gtk::glib::{self, ControlFlow, SourceId};
+use gtk::prelude::{Cast, WidgetExt};
+use crate::ipc::hyprland_ipc_client::HyprlandIpcClient;
+use crate::ipc::live_settings_applier::LiveSettingsApplier;
+use crate::models::monitor::monitor_configuration::MonitorConfiguration;
+use crate::models::settings::monitor_settings::MonitorSettings;
+use crate::persistence::settings_error::SettingsError;
+use crate::providers::application_provider::ApplicationProvider;
+use crate::ui::controls::Control;
+use crate::ui::controls::error_dialog::ErrorDialog;
+use crate::ui::controls::monitor_try_dialog::MonitorTryDialog;
+use crate::ui::pages::monitors::Monitors;
+use crate::ui::states::monitor_try_dialog_state::MonitorTryDialogState;
+use crate::ui::states::monitors_state::MonitorsState;
+use crate::ui::updatable_control::UpdatableControl;
+use crate::utils::{new_rc_mut, RcMut};
+
+const MONITOR_TRY_SECONDS: u32 = 15;
+
+#[derive(Clone)]
+pub struct MonitorsManager {
+    monitors: RcMut<Monitors>,
+    application_provider: ApplicationProvider,
+    previous_monitor_configurations: RcMut<BTreeMap<String, MonitorConfiguration>>,
+    layout_profile_monitor_identities: RcMut<Option<BTreeSet<String>>>,
+    monitor_try_dialog: RcMut<Option<MonitorTryDialog>>,
+    countdown_source: RcMut<Option<SourceId>>,
+}
+
+pub enum MonitorsManagerEvent {
+    TryMonitorSettings,
+    KeepMonitorSettings,
+    RevertMonitorSettings,
+    RescanMonitors,
+    ApplyLayoutProfile,
+}
+
+impl MonitorsManager {
+    pub fn new(monitors: RcMut<Monitors>, application_provider: ApplicationProvider) -> Self {
+        Self {
+            monitors,
+            application_provider,
+            previous_monitor_configurations: new_rc_mut(BTreeMap::new()),
+            layout_profile_monitor_identities: new_rc_mut(None),
+            monitor_try_dialog: new_rc_mut(None),
+            countdown_source: new_rc_mut(None),
+        }
+    }
+
+    pub fn send_event(&self, event: MonitorsManagerEvent) {
+        match event {
+            MonitorsManagerEvent::TryMonitorSettings => {
+                let Some(hyprland_ipc_client) = HyprlandIpcClient::from_environment() else {
+                    return;
+                };
+
+                // The applied monitor settings are captured before trying, so a revert returns to exactly them
+                let monitor_provider = self.application_provider.get_monitor_provider();
+                let previous_monitor_configurations = monitor_provider.borrow().get_live_monitor_configurations();
+                *self.previous_monitor_configurations.borrow_mut() = previous_monitor_configurations.clone();
+
+                let monitor_settings = monitor_provider.borrow().get_settings();
+                if let Err(error) = LiveSettingsApplier::apply_monitor_settings(&hyprland_ipc_client, &monitor_settings) {
+                    let previous_monitor_settings = MonitorSettings::new(previous_monitor_configurations);
+                    let _ = LiveSettingsApplier::apply_monitor_settings(&hyprland_ipc_client, &previous_monitor_settings);
+                    self.present_errors("The monitor settings could not be applied.", &[error]);
+                    return;
+                }
+
+                self.present_monitor_try_dialog();
+            },
+            MonitorsManagerEvent::KeepMonitorSettings => {
+                self.finish_monitor_try();
+            },
+            MonitorsManagerEvent::RevertMonitorSettings => {
+                self.finish_monitor_try();
+
+                // Only the monitor configurations are reverted, layout profiles stay as they are
+                let previous_monitor_configurations = self.previous_monitor_configurations.borrow().clone();
+                self.application_provider.get_monitor_provider().borrow_mut()
+                    .set_monitor_configurations(previous_monitor_configurations.clone());
+
+                let previous_monitor_settings = MonitorSettings::new(previous_monitor_configurations);
+                if let Some(hyprland_ipc_client) = HyprlandIpcClient::from_environment()
+                    && let Err(error) = LiveSettingsApplier::apply_monitor_settings(
+                        &hyprland_ipc_client, &previous_monitor_settings
+                    )
+                {
+                    self.present_errors("The previous monitor settings could not be restored.", &[error]);
+                }
+
+                let state = MonitorsState::from(&self.application_provider);
+                self.monitors.borrow_mut().update_state(state);
+            },
+            MonitorsManagerEvent::RescanMonitors => {
+                let has_detected_monitors = self.application_provider.get_monitor_provider().borrow_mut()
+                    .rescan_monitors();
+                if !has_detected_monitors {
+                    ErrorDialog::present_message(
+                        self.monitors.borrow().get_widget(),
+                        "No monitors were detected.",
+                        "Neither hyprctl nor wlr-randr reported a monitor, the monitor settings were not changed."
+                    );
+                    return;
+                }
+
+                // A different set of connected monitors brings its own layout profile
+                if self.has_monitor_identities_changed() {
+                    self.apply_layout_profile();
+                }
+
+                let state = MonitorsState::from(&self.application_provider);
+                self.monitors.borrow_mut().update_state(state);
+            },
+            MonitorsManagerEvent::ApplyLayoutProfile => {
+                // Trying monitor settings must not be interrupted by a layout profile and the profile is only applied
+                // when the page is opened the first time, so unsaved changes survive switching pages
+                if self.monitor_try_dialog.borrow().is_some() || self.layout_profile_monitor_identities.borrow().is_some() {
+                    return;
+                }
+
+                if !self.application_provider.get_monitor_provider().borrow_mut().rescan_monitors() {
+                    return;
+                }
+                self.apply_layout_profile();
+
+                let state = MonitorsState::from(&self.application_provider);
+                self.monitors.borrow_mut().update_state(state);
+            }
+        }
+    }
+
+    fn apply_layout_profile(&self) {
+        let monitor_provider = self.application_provider.get_monitor_provider();
+        monitor_provider.borrow_mut().apply_matching_layout_profile();
+        *self.layout_profile_monitor_identities.borrow_mut() = monitor_provider.borrow().get_detected_monitor_identities();
+    }
+
+    fn has_monitor_identities_changed(&self) -> bool {
+        let monitor_identities = self.application_provider.get_monitor_provider().borrow()
+            .get_detected_monitor_identities();
+        *self.layout_profile_monitor_identities.borrow() != monitor_identities
+    }
+
+    fn present_monitor_try_dialog(&self) {
+        let parent_window = self.monitors.borrow().get_widget().root()
+            .and_then(|root| root.downcast::<Window>().ok());
+
+        let mut monitor_try_dialog = MonitorTryDialog::new(parent_window);
+        monitor_try_dialog.update_state(MonitorTryDialogState {
+            remaining_seconds: MONITOR_TRY_SECONDS,
+        });
+
+        let monitors_manager = self.clone();
+        monitor_try_dialog.set_keep_click(move || {
+            monitors_manager.send_event(MonitorsManagerEvent::KeepMonitorSettings);
+        });
+
+        let monitors_manager = self.clone();
+        monitor_try_dialog.set_revert_click(move || {
+            monitors_manager.send_event(MonitorsManagerEvent::RevertMonitorSettings);
+        });
+
+        monitor_try_dialog.present();
+        *self.monitor_try_dialog.borrow_mut() = Some(monitor_try_dialog);
+
+        let monitors_manager = self.clone();
+        let countdown_source = glib::timeout_add_seconds_local(1, move || monitors_manager.count_down());
+        *self.countdown_source.borrow_mut() = Some(countdown_source);
+    }
+
+    fn count_down(&self) -> ControlFlow {
+        let mut monitor_try_dialog = self.monitor_try_dialog.borrow_mut();
+        let Some(monitor_try_dialog) = monitor_try_dialog.as_mut() else {
+            return ControlFlow::Break;
+        };
+
+        let remaining_seconds = monitor_try_dialog.get_current_state().remaining_seconds.saturating_sub(1);
+        monitor_try_dialog.update_state(MonitorTryDialogState {
+            remaining_seconds,
+        });
+
+        if remaining_seconds > 0 {
+            return ControlFlow::Continue;
+        }
+
+        // The countdown source is removed by returning Break, so it must not be removed a second time
+        self.countdown_source.borrow_mut().take();
+        let monitors_manager = self.clone();
+        glib::idle_add_local_once(move || {
+            monitors_manager.send_event(MonitorsManagerEvent::RevertMonitorSettings);
+        });
+
+        ControlFlow::Break
+    }
+
+    fn finish_monitor_try(&self) {
+        let countdown_source = self.countdown_source.borrow_mut().take();
+        if let Some(countdown_source) = countdown_source {
+            countdown_source.remove();
+        }
+
+        let monitor_try_dialog = self.monitor_try_dialog.borrow_mut().take();
+        if let Some(monitor_try_dialog) = monitor_try_dialog {
+            monitor_try_dialog.close();
+        }
+    }
+
+    fn present_errors(&self, message: &str, settings_errors: &[SettingsError]) {
+        ErrorDialog::present(self.monitors.borrow().get_widget(), message, settings_errors);
+    }
+}
\ No newline at end of file
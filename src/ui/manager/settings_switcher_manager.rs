@@ -1,22 +1,45 @@
 use std::cell::RefCell;
 use std::rc::Rc;
+use gtk::Window;
+use gtk::prelude::{Cast, WidgetExt};
+use crate::ipc::hyprland_ipc_client::HyprlandIpcClient;
+use crate::ipc::live_settings_applier::LiveSettingsApplier;
 use crate::providers::application_provider::ApplicationProvider;
-use crate::persistence::hyprland_settings_writer::HyprlandSettingsWriter;
-use crate::persistence::settings_writer::SettingsWriter;
-use crate::persistence::yaml_settings_writer::YamlSettingsWriter;
+use crate::persistence::config_backup::ConfigBackup;
+use crate::persistence::config_renderer::ConfigRenderer;
+use crate::persistence::config_snapshot::ConfigSnapshot;
+use crate::persistence::settings_diagnostic::SettingsDiagnostic;
+use crate::persistence::settings_error::SettingsError;
+use crate::persistence::settings_profiles::SettingsProfiles;
+use crate::persistence::settings_validator::SettingsValidator;
 use crate::models::settings::hyprland_settings::HyprlandSettings;
-use crate::persistence::hyprlock_settings_writer::HyprlockSettingsWriter;
-use crate::persistence::hyprpaper_settings_writer::HyprpaperSettingsWriter;
+use crate::ui::controls::Control;
+use crate::ui::controls::error_dialog::ErrorDialog;
+use crate::ui::controls::profile_selector::ProfileSelector;
+use crate::ui::controls::save_preview_dialog::SavePreviewDialog;
 use crate::ui::controls::settings_switcher::SettingsSwitcher;
+use crate::ui::controls::stale_banner::StaleBanner;
+use crate::ui::config_file_watcher::ConfigFileWatcher;
+use crate::ui::field_highlighter::FieldHighlighter;
+use crate::ui::managed_control::ManagedControl;
 use crate::ui::manager::control_manager::ControlManager;
+use crate::ui::pages::app::App;
 use crate::ui::updatable_control::UpdatableControl;
+use crate::ui::states::profile_selector_state::ProfileSelectorState;
+use crate::ui::states::save_preview_dialog_state::SavePreviewDialogState;
 use crate::ui::states::settings_switcher_state::SettingsSwitcherState;
-use crate::utils::RcMut;
+use crate::ui::states::stale_banner_state::StaleBannerState;
+use crate::utils::{new_rc_mut, RcMut};
 
 #[derive(Clone)]
 pub struct SettingsSwitcherManager {
     settings_switcher: RcMut<SettingsSwitcher>,
-    application_provider: ApplicationProvider
+    stale_banner: RcMut<StaleBanner>,
+    profile_selector: RcMut<ProfileSelector>,
+    application_provider: ApplicationProvider,
+    config_snapshot: RcMut<ConfigSnapshot>,
+    config_file_watcher: Rc<ConfigFileWatcher>,
+    field_highlighter: FieldHighlighter,
 }
 
 impl ControlManager<SettingsSwitcher, SettingsSwitcherEvent> for SettingsSwitcherManager {
@@ -27,49 +50,109 @@ impl ControlManager<SettingsSwitcher, SettingsSwitcherEvent> for SettingsSwitche
                 let mut settings_switcher = self.settings_switcher.borrow_mut();
                 settings_switcher.update_state(settings_switcher_state);
             },
+            SettingsSwitcherEvent::ReviewSettings => {
+                if !self.is_yaml_settings_loaded() {
+                    return;
+                }
+
+                let hyprland_settings = self.create_hyprland_settings();
+                let Some(diagnostics) = self.validate_settings(&hyprland_settings) else {
+                    return;
+                };
+
+                let rendered_configs = match ConfigRenderer::render_all_configs(&hyprland_settings) {
+                    Ok(rendered_configs) => rendered_configs,
+                    Err(error) => {
+                        self.present_errors("Cannot prepare the config files.", &[error]);
+                        return;
+                    }
+                };
+
+                let parent_window = self.settings_switcher.borrow().get_widget().root()
+                    .and_then(|root| root.downcast::<Window>().ok());
+
+                let mut save_preview_dialog = SavePreviewDialog::new(parent_window);
+                let mut save_preview_dialog_state = SavePreviewDialogState::from(&rendered_configs);
+                save_preview_dialog_state.warnings = diagnostics.iter()
+                    .map(|diagnostic| diagnostic.to_string())
+                    .collect();
+                save_preview_dialog.update_state(save_preview_dialog_state);
+
+                let settings_switcher_manager = self.clone();
+                save_preview_dialog.set_save_click(move || {
+                    settings_switcher_manager.send_event(SettingsSwitcherEvent::SaveSettings);
+                });
+                save_preview_dialog.present();
+            },
             SettingsSwitcherEvent::SaveSettings => {
-                let program_settings = self.application_provider
-                    .get_program_provider().borrow().get_settings();
-
-                let monitor_settings = self.application_provider
-                    .get_monitor_provider().borrow().get_settings();
-                
-                let appearance_settings = self.application_provider
-                    .get_appearance_provider().borrow().get_settings();
-
-                let input_settings = self.application_provider
-                    .get_input_provider().borrow().get_settings();
-
-                let keybind_settings = self.application_provider
-                    .get_keybinds_provider().borrow().get_settings();
-
-                let lockscreen_settings = self.application_provider
-                    .get_lockscreen_provider().borrow().get_settings();
-
-                let hyprland_settings = HyprlandSettings::new(
-                    program_settings.clone(),
-                    monitor_settings.clone(),
-                    appearance_settings.clone(),
-                    input_settings.clone(),
-                    keybind_settings.clone(),
-                    lockscreen_settings.clone()
-                );
-
-                let mut yaml_settings_writer = YamlSettingsWriter::new();
-                yaml_settings_writer.serialize_settings(hyprland_settings.clone());
-                yaml_settings_writer.write_to_config();
-
-                let mut hyprland_settings_writer = HyprlandSettingsWriter::new();
-                hyprland_settings_writer.serialize_settings(hyprland_settings.clone());
-                hyprland_settings_writer.write_to_config();
-
-                let mut hyprpaper_settings_writer = HyprpaperSettingsWriter::new();
-                hyprpaper_settings_writer.serialize_settings(appearance_settings.clone());
-                hyprpaper_settings_writer.write_to_config();
-
-                let mut hyprlock_settings_writer = HyprlockSettingsWriter::new();
-                hyprlock_settings_writer.serialize_settings(lockscreen_settings.clone());
-                hyprlock_settings_writer.write_to_config();
+                if self.validate_settings(&self.create_hyprland_settings()).is_none() {
+                    return;
+                }
+
+                // The layout profile is stored before writing so it is saved along, and dropped again if writing fails
+                let monitor_provider = self.application_provider.get_monitor_provider();
+                let previous_monitor_settings = monitor_provider.borrow().get_settings();
+                monitor_provider.borrow_mut().store_layout_profile();
+
+                let hyprland_settings = self.create_hyprland_settings();
+                if !self.write_configs(&hyprland_settings) {
+                    monitor_provider.borrow_mut().set_settings(previous_monitor_settings);
+                    return;
+                }
+
+                if let Some(active_profile) = SettingsProfiles::get_active_profile()
+                    && let Err(error) = SettingsProfiles::save_profile(&active_profile, &hyprland_settings)
+                {
+                    self.present_errors("The settings were saved but the active profile could not be updated.", &[error]);
+                }
+            },
+            SettingsSwitcherEvent::ReloadSettings => {
+                let settings_errors = self.application_provider.reload_settings();
+                self.present_errors("Some config files could not be loaded.", &settings_errors);
+
+                let settings_switcher_state = self.settings_switcher.borrow().get_current_state();
+                self.settings_switcher.borrow_mut().remove_controls();
+                self.field_highlighter.clear_fields();
+                App::insert_pages(&self.settings_switcher, self.clone(), self.application_provider.clone());
+                self.settings_switcher.borrow_mut().update_state(settings_switcher_state);
+                self.refresh_config_snapshot();
+            },
+            SettingsSwitcherEvent::ConfigFilesChanged => {
+                let changed_configs = self.config_snapshot.borrow().get_changed_configs();
+                self.stale_banner.borrow_mut().update_state(StaleBannerState {
+                    changed_configs,
+                });
+            },
+            SettingsSwitcherEvent::KeepSessionSettings => {
+                self.refresh_config_snapshot();
+            },
+            SettingsSwitcherEvent::ActivateProfile => {
+                let selected_profile = self.profile_selector.borrow().get_selected_profile();
+                if let Err(error) = self.activate_profile(&selected_profile) {
+                    self.present_errors("Cannot activate the profile.", &[error]);
+                }
+                self.refresh_profile_selector();
+            },
+            SettingsSwitcherEvent::CloneProfile => {
+                if let Err(error) = self.clone_profile() {
+                    self.present_errors("Cannot clone the profile.", &[error]);
+                }
+                self.refresh_profile_selector();
+            },
+            SettingsSwitcherEvent::RenameProfile => {
+                let selected_profile = self.profile_selector.borrow().get_selected_profile();
+                let profile_name = self.profile_selector.borrow().get_profile_name();
+                if let Err(error) = SettingsProfiles::rename_profile(&selected_profile, profile_name.trim()) {
+                    self.present_errors("Cannot rename the profile.", &[error]);
+                }
+                self.refresh_profile_selector();
+            },
+            SettingsSwitcherEvent::DeleteProfile => {
+                let selected_profile = self.profile_selector.borrow().get_selected_profile();
+                if let Err(error) = SettingsProfiles::delete_profile(&selected_profile) {
+                    self.present_errors("Cannot delete the profile.", &[error]);
+                }
+                self.refresh_profile_selector();
             }
         }
     }
@@ -81,17 +164,204 @@ impl ControlManager<SettingsSwitcher, SettingsSwitcherEvent> for SettingsSwitche
 
 pub enum SettingsSwitcherEvent {
     NewControlName(String),
+    ReviewSettings,
     SaveSettings,
+    ReloadSettings,
+    ConfigFilesChanged,
+    KeepSessionSettings,
+    ActivateProfile,
+    CloneProfile,
+    RenameProfile,
+    DeleteProfile,
 }
 
 impl SettingsSwitcherManager {
     pub fn new(
         settings_switcher: Rc<RefCell<SettingsSwitcher>>,
+        stale_banner: RcMut<StaleBanner>,
+        profile_selector: RcMut<ProfileSelector>,
         application_provider: ApplicationProvider
     ) -> Self {
         Self {
             settings_switcher,
-            application_provider
+            stale_banner,
+            profile_selector,
+            application_provider,
+            config_snapshot: new_rc_mut(ConfigSnapshot::capture()),
+            config_file_watcher: Rc::new(ConfigFileWatcher::new()),
+            field_highlighter: FieldHighlighter::new(),
+        }
+    }
+
+    pub fn get_field_highlighter(&self) -> FieldHighlighter {
+        self.field_highlighter.clone()
+    }
+
+    pub fn watch_config_files(&self) {
+        self.config_file_watcher.init_events_by_manager(self.clone());
+    }
+
+    pub fn refresh_profile_selector(&self) {
+        self.profile_selector.borrow_mut().update_state(ProfileSelectorState::from_profiles());
+    }
+
+    fn activate_profile(&self, profile_name: &str) -> Result<(), SettingsError> {
+        let profile_settings = SettingsProfiles::load_profile(profile_name)?;
+
+        // Unsaved changes of the previous profile are kept in its profile file instead of being discarded
+        if let Some(active_profile) = SettingsProfiles::get_active_profile()
+            && active_profile != profile_name
+        {
+            SettingsProfiles::save_profile(&active_profile, &self.create_hyprland_settings())?;
+        }
+
+        if !self.write_configs(&profile_settings) {
+            return Ok(());
+        }
+
+        SettingsProfiles::set_active_profile(profile_name)?;
+        self.send_event(SettingsSwitcherEvent::ReloadSettings);
+        Ok(())
+    }
+
+    fn clone_profile(&self) -> Result<(), SettingsError> {
+        let selected_profile = self.profile_selector.borrow().get_selected_profile();
+        let profile_name = self.profile_selector.borrow().get_profile_name();
+        let profile_name = profile_name.trim();
+        let active_profile = SettingsProfiles::get_active_profile();
+
+        // The active profile is cloned with the unsaved changes of the current session
+        let profile_settings = if selected_profile.is_empty() || active_profile.as_ref() == Some(&selected_profile) {
+            self.create_hyprland_settings()
+        } else {
+            SettingsProfiles::load_profile(&selected_profile)?
+        };
+
+        SettingsProfiles::create_profile(profile_name, &profile_settings)?;
+        if active_profile.is_none() {
+            SettingsProfiles::set_active_profile(profile_name)?;
+        }
+
+        Ok(())
+    }
+
+    fn is_yaml_settings_loaded(&self) -> bool {
+        let Some(yaml_settings_error) = self.application_provider.get_yaml_settings_error() else {
+            return true;
+        };
+
+        self.present_errors(
+            "hyprsettings.yaml could not be loaded and is not overwritten. Fix or remove it and reload the settings.",
+            &[yaml_settings_error]
+        );
+        false
+    }
+
+    fn write_configs(&self, hyprland_settings: &HyprlandSettings) -> bool {
+        if !self.is_yaml_settings_loaded() {
+            return false;
         }
+
+        let backup_settings = self.application_provider
+            .get_backup_provider().borrow().get_settings();
+
+        let rendered_configs = match ConfigRenderer::render_all_configs(hyprland_settings) {
+            Ok(rendered_configs) => rendered_configs,
+            Err(error) => {
+                self.present_errors("Cannot prepare the config files.", &[error]);
+                return false;
+            }
+        };
+
+        if backup_settings.retention_count > 0 {
+            let backup_result = ConfigBackup::new().backup_config_files()
+                .and_then(|_| ConfigBackup::remove_old_backups(backup_settings.retention_count));
+
+            if let Err(error) = backup_result {
+                self.present_errors("Cannot back up the config files, nothing was saved.", &[error]);
+                return false;
+            }
+        }
+
+        let live_settings_applier = LiveSettingsApplier::from_current_config();
+        let settings_errors: Vec<SettingsError> = rendered_configs.iter()
+            .filter_map(|rendered_config| rendered_config.write_to_file().err())
+            .collect();
+        self.refresh_config_snapshot();
+
+        if !settings_errors.is_empty() {
+            self.present_errors("Some config files could not be saved.", &settings_errors);
+            return false;
+        }
+
+        if let Some(hyprland_ipc_client) = HyprlandIpcClient::from_environment()
+            && let Err(error) = live_settings_applier.apply(&hyprland_ipc_client, hyprland_settings)
+        {
+            self.present_errors("The settings were saved but could not be applied to Hyprland.", &[error]);
+        }
+
+        true
+    }
+
+    fn refresh_config_snapshot(&self) {
+        *self.config_snapshot.borrow_mut() = ConfigSnapshot::capture();
+        self.stale_banner.borrow_mut().update_state(StaleBannerState::default());
+    }
+
+    fn create_hyprland_settings(&self) -> HyprlandSettings {
+        let program_settings = self.application_provider
+            .get_program_provider().borrow().get_settings();
+
+        let monitor_settings = self.application_provider
+            .get_monitor_provider().borrow().get_settings();
+
+        let appearance_settings = self.application_provider
+            .get_appearance_provider().borrow().get_settings();
+
+        let input_settings = self.application_provider
+            .get_input_provider().borrow().get_settings();
+
+        let keybind_settings = self.application_provider
+            .get_keybinds_provider().borrow().get_settings();
+
+        let lockscreen_settings = self.application_provider
+            .get_lockscreen_provider().borrow().get_settings();
+
+        let backup_settings = self.application_provider
+            .get_backup_provider().borrow().get_settings();
+
+        let config_file_settings = self.application_provider
+            .get_config_file_provider().borrow().get_settings();
+
+        HyprlandSettings::new(
+            program_settings,
+            monitor_settings,
+            appearance_settings,
+            input_settings,
+            keybind_settings,
+            lockscreen_settings,
+            backup_settings,
+            config_file_settings
+        )
+    }
+
+    fn validate_settings(&self, hyprland_settings: &HyprlandSettings) -> Option<Vec<SettingsDiagnostic>> {
+        let diagnostics = SettingsValidator::validate(hyprland_settings);
+        self.field_highlighter.highlight_diagnostics(&diagnostics);
+
+        if SettingsValidator::has_errors(&diagnostics) {
+            let settings_errors: Vec<SettingsError> = diagnostics.iter()
+                .filter(|diagnostic| diagnostic.is_error())
+                .map(SettingsError::validation)
+                .collect();
+            self.present_errors("Some settings are invalid, nothing was saved.", &settings_errors);
+            return None;
+        }
+
+        Some(diagnostics)
+    }
+
+    fn present_errors(&self, message: &str, settings_errors: &[SettingsError]) {
+        ErrorDialog::present(self.settings_switcher.borrow().get_widget(), message, settings_errors);
     }
 }
\ No newline at end of file
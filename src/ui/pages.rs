@@ -15,6 +15,7 @@ pub mod overview;
 pub mod wallpaper;
 pub mod lockscreen;
 pub mod input;
+pub mod backups;
 
 pub const OVERVIEW_PAGE: &str = "overview-page";
 pub const USER_PROGRAMS_PAGE: &str = "user-programs-page";
@@ -25,6 +26,7 @@ pub const APPEARANCE_PAGE: &str = "appearance-page";
 pub const STARTUP_PROGRAMS_PAGE: &str = "startup-page";
 pub const INPUT_PAGE: &str = "input-page";
 pub const KEYBINDS_PAGE: &str = "keybinds-page";
+pub const BACKUPS_PAGE: &str = "backups-page";
 pub const INFO_PAGE: &str = "info-page";
 
 pub struct Pages;
@@ -2,6 +2,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 use gtk::Orientation;
 use gtk::prelude::BoxExt;
+use crate::persistence::settings_error::SettingsError;
 use crate::providers::application_provider::ApplicationProvider;
 use crate::types::GTKBox;
 use crate::ui::box_builder::BoxBuilder;
@@ -9,31 +10,40 @@ use crate::ui::boxes::DEFAULT_MARGIN;
 use crate::ui::manager::settings_switcher_manager::SettingsSwitcherManager;
 use crate::ui::pages::user_programs::UserPrograms;
 use crate::ui::controls::navigation::Navigation;
+use crate::ui::controls::profile_selector::ProfileSelector;
 use crate::ui::controls::settings_switcher::SettingsSwitcher;
+use crate::ui::controls::stale_banner::StaleBanner;
 use crate::ui::states::programs_state::ProgramsState;
 use crate::ui::controls::Control;
+use crate::ui::controls::error_dialog::ErrorDialog;
 use crate::ui::managed_control::ManagedControl;
 use crate::ui::manager::input_manager::InputManager;
+use crate::ui::manager::lockscreen_manager::LockscreenManager;
+use crate::ui::manager::backups_manager::BackupsManager;
+use crate::ui::manager::monitors_manager::MonitorsManager;
 use crate::ui::pages::appearance::Appearance;
+use crate::ui::pages::backups::Backups;
 use crate::ui::pages::monitors::Monitors;
 use crate::ui::pages::infos::Infos;
 use crate::ui::pages::keybinds::Keybinds;
 use crate::ui::pages::lockscreen::Lockscreen;
 use crate::ui::pages::overview::Overview;
-use crate::ui::pages::{APPEARANCE_PAGE, MONITORS_PAGE, USER_PROGRAMS_PAGE, INFO_PAGE, INPUT_PAGE, KEYBINDS_PAGE, LOCKSCREEN_PAGE, OVERVIEW_PAGE, STARTUP_PROGRAMS_PAGE, WALLPAPER_PAGE};
+use crate::ui::pages::{APPEARANCE_PAGE, BACKUPS_PAGE, MONITORS_PAGE, USER_PROGRAMS_PAGE, INFO_PAGE, INPUT_PAGE, KEYBINDS_PAGE, LOCKSCREEN_PAGE, OVERVIEW_PAGE, STARTUP_PROGRAMS_PAGE, WALLPAPER_PAGE};
 use crate::ui::pages::input::Input;
 use crate::ui::pages::startup_programs::StartupPrograms;
 use crate::ui::pages::wallpaper::Wallpaper;
 use crate::ui::states::monitors_state::MonitorsState;
+use crate::ui::states::backups_page_state::BackupsPageState;
 use crate::ui::states::input_state::InputState;
 use crate::ui::states::lockscreen_page_state::LockScreenPageState;
 use crate::ui::states::settings_switcher_state::SettingsSwitcherState;
 use crate::ui::states::wallpaper_page_state::WallpaperPageState;
 use crate::ui::updatable_control::UpdatableControl;
-use crate::utils::new_rc_mut;
+use crate::utils::{new_rc_mut, RcMut};
 
 pub struct App {
-    app_box: GTKBox
+    app_box: GTKBox,
+    settings_errors: Vec<SettingsError>,
 }
 
 impl Control for App {
@@ -51,6 +61,51 @@ impl App {
             .set_margin(DEFAULT_MARGIN)
             .build();
 
+        let settings_switcher = Rc::new(RefCell::new(SettingsSwitcher::new()));
+        let stale_banner = new_rc_mut(StaleBanner::new());
+        let profile_selector = new_rc_mut(ProfileSelector::new());
+        let settings_switcher_manager = SettingsSwitcherManager::new(
+            settings_switcher.clone(), stale_banner.clone(), profile_selector.clone(), application_provider.clone()
+        );
+        stale_banner.borrow().init_events_by_manager(settings_switcher_manager.clone());
+        profile_selector.borrow().init_events_by_manager(settings_switcher_manager.clone());
+        settings_switcher_manager.refresh_profile_selector();
+        settings_switcher_manager.watch_config_files();
+
+        Self::insert_pages(&settings_switcher, settings_switcher_manager.clone(), application_provider.clone());
+
+        let settings_switcher_state = SettingsSwitcherState::new(OVERVIEW_PAGE.to_string());
+        settings_switcher.borrow_mut().update_state(settings_switcher_state);
+        
+        let navigation = Navigation::new(settings_switcher_manager.clone());
+        navigation.init_events();
+        navigation.get_widget().append(profile_selector.borrow().get_widget());
+
+        let content_box = BoxBuilder::new("content")
+            .set_orientation(Orientation::Vertical)
+            .build();
+        content_box.append(stale_banner.borrow().get_widget());
+        content_box.append(settings_switcher.borrow().get_widget());
+
+        app_box.append(navigation.get_widget());
+        app_box.append(&content_box);
+
+        Self {
+            app_box,
+            settings_errors: application_provider.get_settings_errors(),
+        }
+    }
+
+    pub fn present_settings_errors(&self) {
+        ErrorDialog::present(&self.app_box, "Some config files could not be loaded.", &self.settings_errors);
+    }
+
+    pub fn insert_pages(
+        settings_switcher: &RcMut<SettingsSwitcher>,
+        settings_switcher_manager: SettingsSwitcherManager,
+        application_provider: ApplicationProvider
+    ) {
+        let field_highlighter = settings_switcher_manager.get_field_highlighter();
         let overview_settings = new_rc_mut(Overview::new(application_provider.clone()));
 
         let state = ProgramsState::from(&application_provider);
@@ -58,21 +113,27 @@ impl App {
         user_programs.borrow_mut().update_state(state);
 
         let state = MonitorsState::from(&application_provider);
-        let monitors = new_rc_mut(Monitors::new(application_provider.clone()));
+        let monitors = new_rc_mut(Monitors::new(application_provider.clone(), field_highlighter.clone()));
         monitors.borrow_mut().update_state(state);
 
+        let monitors_manager = MonitorsManager::new(monitors.clone(), application_provider.clone());
+        monitors.borrow().init_events_by_manager(monitors_manager);
+
         let state = WallpaperPageState::from(&application_provider);
-        let wallpaper = new_rc_mut(Wallpaper::new(application_provider.clone()));
+        let wallpaper = new_rc_mut(Wallpaper::new(application_provider.clone(), field_highlighter.clone()));
         wallpaper.borrow_mut().update_state(state.clone());
 
         let state = LockScreenPageState::from(&application_provider);
         let lockscreen = new_rc_mut(Lockscreen::new(application_provider.clone()));
         lockscreen.borrow_mut().update_state(state.clone());
 
-        let appearance = new_rc_mut(Appearance::new(application_provider.clone()));
+        let lockscreen_manager = LockscreenManager::new(lockscreen.clone(), application_provider.clone());
+        lockscreen.borrow().init_events_by_manager(lockscreen_manager);
+
+        let appearance = new_rc_mut(Appearance::new(application_provider.clone(), &field_highlighter));
 
         let state = InputState::from(&application_provider);
-        let input = new_rc_mut(Input::new(application_provider.clone()));
+        let input = new_rc_mut(Input::new(application_provider.clone(), &field_highlighter));
         input.borrow_mut().update_state(state.clone());
 
         let input_manager = InputManager::new(input.clone());
@@ -83,9 +144,17 @@ impl App {
         let startup_program = new_rc_mut(StartupPrograms::new());
         startup_program.borrow_mut().init_events(application_provider.clone());
         startup_program.borrow_mut().init_ui(application_provider.clone());
+
+        let state = BackupsPageState::from(&application_provider);
+        let backups = new_rc_mut(Backups::new(application_provider.clone()));
+        backups.borrow_mut().update_state(state);
+
+        let backups_manager = BackupsManager::new(
+            backups.clone(), settings_switcher_manager, application_provider.clone()
+        );
+        backups.borrow().init_events_by_manager(backups_manager);
         
         let infos = new_rc_mut(Infos::new());
-        let settings_switcher = Rc::new(RefCell::new(SettingsSwitcher::new()));
         settings_switcher.borrow_mut()
             .insert_control(OVERVIEW_PAGE.to_string(), overview_settings)
             .insert_control(USER_PROGRAMS_PAGE.to_string(), user_programs)
@@ -96,20 +165,7 @@ impl App {
             .insert_control(KEYBINDS_PAGE.to_string(), keybinds)
             .insert_control(STARTUP_PROGRAMS_PAGE.to_string(), startup_program)
             .insert_control(INPUT_PAGE.to_string(), input)
+            .insert_control(BACKUPS_PAGE.to_string(), backups)
             .insert_control(INFO_PAGE.to_string(), infos);
-
-        let settings_switcher_state = SettingsSwitcherState::new(OVERVIEW_PAGE.to_string());
-        settings_switcher.borrow_mut().update_state(settings_switcher_state);
-        
-        let settings_switcher_manager = SettingsSwitcherManager::new(settings_switcher.clone(), application_provider.clone());
-        let navigation = Navigation::new(settings_switcher_manager.clone());
-        navigation.init_events();
-
-        app_box.append(navigation.get_widget());
-        app_box.append(settings_switcher.borrow().get_widget());
-
-        Self {
-            app_box
-        }
     }
 }
\ No newline at end of file
@@ -10,6 +10,7 @@ use crate::ui::controls::color_selector::ColorSelector;
 use crate::ui::controls::input_field::InputField;
 use crate::ui::controls::selection_box::SelectionBox;
 use crate::ui::controls::Control;
+use crate::ui::field_highlighter::FieldHighlighter;
 use crate::ui::section_box_builder::SectionBoxBuilder;
 use crate::ui::controls::spin_button::SpinButton;
 use crate::ui::labeled_control::LabeledControl;
@@ -32,13 +33,13 @@ impl Control for Appearance {
 }
 
 impl Appearance {
-    pub fn new(application_provider: ApplicationProvider) -> Self {
+    pub fn new(application_provider: ApplicationProvider, field_highlighter: &FieldHighlighter) -> Self {
         const APPEARANCE_NAME: &str = "appearance";
 
         let wallpaper_section = Appearance::create_wallpaper_section_box(&application_provider);
         let cursor_section = Appearance::create_cursor_section_box(&application_provider);
         let styling_section = Appearance::create_styling_section_box(&application_provider);
-        let decoration_section = Appearance::create_decorations_section_box(&application_provider);
+        let decoration_section = Appearance::create_decorations_section_box(&application_provider, field_highlighter);
         let animations_section = Appearance::create_animations_section_box(&application_provider);
         let layouts_section = Appearance::create_layouts_section_box(&application_provider);
 
@@ -193,15 +194,15 @@ impl Appearance {
         let appearance_provider = application_provider.get_appearance_provider();
         let appearance_provider_clone = appearance_provider.clone();
         let inner_gap_spin_button_change = move |spin_button: &GTKSpinButton| {
-            appearance_provider_clone.borrow_mut().set_inner_gab(spin_button.value());
+            appearance_provider_clone.borrow_mut().set_inner_gap(spin_button.value());
         };
         let mut inner_gap_spin_button = SpinButton::new();
         inner_gap_spin_button.set_text_width(APPEARANCE_LABEL_WIDTH);
         let state = SpinButtonState {
-            label_text:  "Inner gab:".to_string(),
+            label_text:  "Inner gap:".to_string(),
             min_value: 0.0,
             max_value: 100.0,
-            current_value: appearance_provider.borrow().get_inner_gab(),
+            current_value: appearance_provider.borrow().get_inner_gap(),
             increment_value: 0.1,
             page_increment_value: 1.0,
             page_size: 0.0,
@@ -215,15 +216,15 @@ impl Appearance {
         // Outer gap option
         let appearance_provider_clone = appearance_provider.clone();
         let outer_gap_spin_button_change = move |spin_button: &GTKSpinButton| {
-            appearance_provider_clone.borrow_mut().set_outer_gab(spin_button.value());
+            appearance_provider_clone.borrow_mut().set_outer_gap(spin_button.value());
         };
         let mut outer_gap_spin_button = SpinButton::new();
         outer_gap_spin_button.set_text_width(APPEARANCE_LABEL_WIDTH);
         let state = SpinButtonState {
-            label_text: "Outer gab:".to_string(),
+            label_text: "Outer gap:".to_string(),
             min_value: 0.0,
             max_value: 100.0,
-            current_value: appearance_provider.borrow().get_outer_gab(),
+            current_value: appearance_provider.borrow().get_outer_gap(),
             increment_value: 0.1,
             page_increment_value: 1.0,
             page_size: 0.0,
@@ -324,7 +325,7 @@ impl Appearance {
         styling_section_box
     }
 
-    fn create_decorations_section_box(application_provider: &ApplicationProvider) -> GTKBox {
+    fn create_decorations_section_box(application_provider: &ApplicationProvider, field_highlighter: &FieldHighlighter) -> GTKBox {
         const DECORATION_LABEL: &str = "Decoration";
         let decorations_section_box = SectionBoxBuilder::new("decorations-section", 0)
             .create_header_elements(DECORATION_LABEL)
@@ -602,6 +603,9 @@ impl Appearance {
         decorations_section_box.append(blur_size_spin_button.get_widget());
         decorations_section_box.append(blur_passes_spin_button.get_widget());
         decorations_section_box.append(blur_vibrancy_spin_button.get_widget());
+
+        field_highlighter.register_field("appearance_settings.active_opacity", active_opacity_spin_button.get_widget());
+        field_highlighter.register_field("appearance_settings.inactive_opacity", inactive_opacity_spin_button.get_widget());
         decorations_section_box
     }
 
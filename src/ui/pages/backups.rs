@@ -0,0 +1,288 @@
+use gtk::{Align, Button, DropDown, Label, Orientation, ScrolledWindow, TextView};
+use gtk::prelude::{BoxExt, ButtonExt, TextBufferExt, TextViewExt, WidgetExt};
+use crate::persistence::config_backup::{ConfigBackup, BACKUP_CONFIG_FILES};
+use crate::persistence::config_diff::ConfigDiff;
+use crate::providers::application_provider::ApplicationProvider;
+use crate::types::{GTKBox, GTKSpinButton};
+use crate::ui::box_builder::BoxBuilder;
+use crate::ui::boxes::{Boxes, DEFAULT_MARGIN};
+use crate::ui::controls::Control;
+use crate::ui::controls::selection_box::SelectionBox;
+use crate::ui::controls::spin_button::SpinButton;
+use crate::ui::labeled_control::LabeledControl;
+use crate::ui::managed_control::ManagedControl;
+use crate::ui::manager::backups_manager::{BackupsManager, BackupsManagerEvent};
+use crate::ui::section_box_builder::SectionBoxBuilder;
+use crate::ui::states::backups_page_state::BackupsPageState;
+use crate::ui::states::selection_box_state::SelectionBoxState;
+use crate::ui::states::spin_button_state::SpinButtonState;
+use crate::ui::updatable_control::UpdatableControl;
+
+const BACKUPS_LABEL_WIDTH: u32 = 220;
+const DIFF_CONTEXT_LINE_COUNT: usize = 2;
+
+pub struct Backups {
+    state: BackupsPageState,
+    backups_scroll_box: GTKBox,
+    backup_list_box: GTKBox,
+    retention_count_spin_button: SpinButton,
+    split_config_files_selection_box: SelectionBox,
+    backup_selection_box: SelectionBox,
+    backup_details_box: GTKBox,
+    restore_button: Button,
+}
+
+impl Control for Backups {
+    fn get_widget(&self) -> &GTKBox {
+        &self.backups_scroll_box
+    }
+}
+
+impl UpdatableControl<BackupsPageState> for Backups {
+    fn update_state(&mut self, state: BackupsPageState) {
+        let spin_button_state = SpinButtonState {
+            label_text: "Kept backups".to_string(),
+            min_value: 0.0,
+            max_value: 100.0,
+            current_value: state.retention_count as f64,
+            increment_value: 1.0,
+            page_increment_value: 5.0,
+            page_size: 0.0,
+            climb_rate: 2.0,
+            digit_count: 0,
+            use_integral_numbers: true,
+        };
+        self.retention_count_spin_button.update_state(spin_button_state);
+
+        let selection_box_state = SelectionBoxState {
+            label_text: "Split hyprland.conf".to_string(),
+            selected_option: Some(state.split_config_files.to_string()),
+            options: SelectionBoxState::get_false_true_options(),
+        };
+        self.split_config_files_selection_box.update_state(selection_box_state);
+
+        Boxes::clear_box_content(&self.backup_list_box);
+        if state.backup_entries.is_empty() {
+            let backups_warning = Boxes::create_warning_box("No backups have been saved yet.");
+            self.backup_list_box.append(&backups_warning);
+        }
+
+        for backup_entry in &state.backup_entries {
+            let backup_entry_box = Self::create_backup_entry_box(
+                backup_entry.get_display_time().as_str(), backup_entry.changed_files.join(", ").as_str()
+            );
+            self.backup_list_box.append(&backup_entry_box);
+        }
+
+        let selected_backup = self.backup_selection_box.get_selected_item();
+        let selection_box_state = SelectionBoxState {
+            label_text: "Save point".to_string(),
+            selected_option: Some(selected_backup),
+            options: state.backup_entries.iter()
+                .map(|backup_entry| backup_entry.name.clone())
+                .collect(),
+        };
+        self.backup_selection_box.update_state(selection_box_state);
+        self.restore_button.set_sensitive(!state.backup_entries.is_empty());
+
+        let selected_backup = self.backup_selection_box.get_selected_item();
+        Self::create_backup_details(&self.backup_details_box, selected_backup.as_str());
+
+        self.state = state;
+    }
+
+    fn get_current_state(&self) -> BackupsPageState {
+        self.state.clone()
+    }
+}
+
+impl ManagedControl<BackupsManager> for Backups {
+    fn init_events_by_manager(&self, backups_manager: BackupsManager) {
+        let backups_manager_clone = backups_manager.clone();
+        self.backups_scroll_box.connect_map(move |_| {
+            backups_manager_clone.send_event(BackupsManagerEvent::RefreshBackups);
+        });
+
+        self.restore_button.connect_clicked(move |_| {
+            backups_manager.send_event(BackupsManagerEvent::RestoreSelectedBackup);
+        });
+    }
+}
+
+impl Backups {
+    pub fn new(application_provider: ApplicationProvider) -> Self {
+        let backups_box = BoxBuilder::new("backups")
+            .set_orientation(Orientation::Vertical)
+            .set_margin(DEFAULT_MARGIN)
+            .build();
+
+        let backups_scroll_window = ScrolledWindow::new();
+        backups_scroll_window.set_widget_name("backups-scroll-window");
+        backups_scroll_window.set_vexpand(true);
+        backups_scroll_window.set_child(Some(&backups_box));
+
+        let backups_scroll_box = BoxBuilder::new("backups-scroll-box")
+            .set_orientation(Orientation::Vertical)
+            .build();
+        backups_scroll_box.append(&backups_scroll_window);
+
+        let backup_settings_section_box = SectionBoxBuilder::new("backup-settings-section", 0)
+            .create_header_elements("Backup settings")
+            .build().expect("Failed to create backup settings section box");
+
+        let retention_count_spin_button = SpinButton::new();
+        retention_count_spin_button.set_text_width(BACKUPS_LABEL_WIDTH);
+
+        let backup_provider = application_provider.get_backup_provider();
+        retention_count_spin_button.set_value_change(move |spin_button: &GTKSpinButton| {
+            backup_provider.borrow_mut().set_retention_count(spin_button.value() as usize);
+        });
+        backup_settings_section_box.append(retention_count_spin_button.get_widget());
+
+        let config_files_section_box = SectionBoxBuilder::new("config-files-section", 0)
+            .create_header_elements("Config files")
+            .build().expect("Failed to create config files section box");
+
+        let split_config_files_label = Label::new(Some(
+            "When splitting is enabled, the monitor, keybind, input, look and feel and autostart settings are written \
+            into monitors.conf, keybinds.conf, input.conf, looknfeel.conf and autostart.conf, which are sourced by \
+            hyprland.conf."
+        ));
+        split_config_files_label.set_xalign(0.0);
+        split_config_files_label.set_wrap(true);
+
+        let split_config_files_selection_box = SelectionBox::new();
+        split_config_files_selection_box.set_text_width(BACKUPS_LABEL_WIDTH);
+
+        let config_file_provider = application_provider.get_config_file_provider();
+        split_config_files_selection_box.set_selection_change(move |dropdown: &DropDown| {
+            let bool_value = SelectionBox::get_selected_option_as_bool(dropdown);
+            config_file_provider.borrow_mut().set_split_config_files(bool_value);
+        });
+        config_files_section_box.append(&split_config_files_label);
+        config_files_section_box.append(split_config_files_selection_box.get_widget());
+
+        let backup_list_section_box = SectionBoxBuilder::new("backup-list-section", 0)
+            .create_header_elements("Save points")
+            .build().expect("Failed to create backup list section box");
+
+        let backup_list_box = BoxBuilder::new("backup-list")
+            .set_orientation(Orientation::Vertical)
+            .build();
+        backup_list_section_box.append(&Self::create_backup_entry_box("Saved at", "Changed files"));
+        backup_list_section_box.append(&backup_list_box);
+
+        let restore_section_box = SectionBoxBuilder::new("backup-restore-section", 0)
+            .create_header_elements("Restore")
+            .build().expect("Failed to create backup restore section box");
+
+        let backup_details_label = Label::new(Some(
+            "Lines marked with + are added and lines marked with - are removed when restoring the selected save point."
+        ));
+        backup_details_label.set_xalign(0.0);
+        backup_details_label.set_wrap(true);
+
+        let backup_details_box = BoxBuilder::new("backup-details")
+            .set_orientation(Orientation::Vertical)
+            .build();
+
+        let backup_selection_box = SelectionBox::new();
+        backup_selection_box.set_text_width(BACKUPS_LABEL_WIDTH);
+
+        let backup_details_box_clone = backup_details_box.clone();
+        backup_selection_box.set_selection_change(move |dropdown: &DropDown| {
+            let selected_backup = SelectionBox::get_selected_option(dropdown);
+            Self::create_backup_details(&backup_details_box_clone, selected_backup.as_str());
+        });
+
+        let restore_button = Button::with_label("Restore this save point");
+        restore_button.set_widget_name("restore-backup-button");
+        restore_button.set_halign(Align::Start);
+
+        restore_section_box.append(backup_selection_box.get_widget());
+        restore_section_box.append(&backup_details_label);
+        restore_section_box.append(&backup_details_box);
+        restore_section_box.append(&restore_button);
+
+        backups_box.append(&config_files_section_box);
+        backups_box.append(&backup_settings_section_box);
+        backups_box.append(&backup_list_section_box);
+        backups_box.append(&restore_section_box);
+
+        let state = Default::default();
+
+        Self {
+            state,
+            backups_scroll_box,
+            backup_list_box,
+            retention_count_spin_button,
+            split_config_files_selection_box,
+            backup_selection_box,
+            backup_details_box,
+            restore_button,
+        }
+    }
+
+    pub fn get_selected_backup(&self) -> String {
+        self.backup_selection_box.get_selected_item()
+    }
+
+    fn create_backup_entry_box(time_text: &str, changed_files_text: &str) -> GTKBox {
+        let backup_entry_box = BoxBuilder::new("backup-entry")
+            .set_orientation(Orientation::Horizontal)
+            .build();
+
+        let time_label = Label::new(Some(time_text));
+        time_label.set_xalign(0.0);
+        time_label.set_width_request(BACKUPS_LABEL_WIDTH as i32);
+
+        let changed_files_label = Label::new(Some(changed_files_text));
+        changed_files_label.set_xalign(0.0);
+
+        backup_entry_box.append(&time_label);
+        backup_entry_box.append(&changed_files_label);
+        backup_entry_box
+    }
+
+    fn create_backup_details(backup_details_box: &GTKBox, backup_name: &str) {
+        Boxes::clear_box_content(backup_details_box);
+        if backup_name.is_empty() {
+            return;
+        }
+
+        let mut has_differences = false;
+        for config_name in BACKUP_CONFIG_FILES {
+            let Some(snapshot_content) = ConfigBackup::get_snapshot_content(backup_name, config_name) else {
+                continue;
+            };
+
+            let current_content = ConfigBackup::get_current_content(config_name);
+
+            let diff_lines = ConfigDiff::create_line_diff(&current_content, &snapshot_content);
+            if !ConfigDiff::has_changes(&diff_lines) {
+                continue;
+            }
+
+            has_differences = true;
+            let config_name_label = Label::new(Some(config_name));
+            config_name_label.set_xalign(0.0);
+
+            let diff_text_view = TextView::new();
+            diff_text_view.set_editable(false);
+            diff_text_view.set_cursor_visible(false);
+            diff_text_view.set_monospace(true);
+            diff_text_view.buffer().set_text(
+                ConfigDiff::create_diff_text(&diff_lines, DIFF_CONTEXT_LINE_COUNT).as_str()
+            );
+
+            backup_details_box.append(&config_name_label);
+            backup_details_box.append(&diff_text_view);
+        }
+
+        if !has_differences {
+            let no_differences_label = Label::new(Some("The current config files match this save point."));
+            no_differences_label.set_xalign(0.0);
+            backup_details_box.append(&no_differences_label);
+        }
+    }
+}
\ No newline at end of file
@@ -5,6 +5,7 @@ use crate::providers::application_provider::ApplicationProvider;
 use crate::providers::monitor_provider::MonitorProvider;
 use crate::types::{GTKBox, GTKSpinButton};
 use crate::ui::box_builder::BoxBuilder;
+use crate::ui::field_highlighter::FieldHighlighter;
 use crate::ui::boxes::DEFAULT_MARGIN;
 use crate::ui::controls::activable_control::ActivableControl;
 use crate::ui::controls::Control;
@@ -309,7 +310,7 @@ impl ManagedControl<InputManager> for Input {
 }
 
 impl Input {
-    pub fn new(application_provider: ApplicationProvider) -> Self {
+    pub fn new(application_provider: ApplicationProvider, field_highlighter: &FieldHighlighter) -> Self {
         let input_box = BoxBuilder::new("input_box")
             .set_orientation(Orientation::Vertical)
             .set_margin(DEFAULT_MARGIN)
@@ -369,6 +370,8 @@ impl Input {
         input_box.append(&tablet_section);
 
         let state = Default::default();
+        field_highlighter.register_field("input_settings.keyboard_layout", layout_input_field.get_widget());
+
         Self {
             state,
             application_provider,
@@ -1,6 +1,7 @@
-use gtk::{ColorDialogButton, DropDown, Entry, Orientation, ScrolledWindow};
-use gtk::prelude::{BoxExt, EditableExt, WidgetExt};
+use gtk::{Align, Button, ColorDialogButton, DropDown, Entry, Orientation, ScrolledWindow};
+use gtk::prelude::{BoxExt, ButtonExt, EditableExt, WidgetExt};
 use crate::models::rgba_color::RGBAColor;
+use crate::persistence::hyprlock_config_reader::HyprlockConfigReader;
 use crate::providers::application_provider::ApplicationProvider;
 use crate::types::{GTKBox, GTKSpinButton};
 use crate::ui::box_builder::BoxBuilder;
@@ -11,6 +12,8 @@ use crate::ui::controls::input_field::InputField;
 use crate::ui::controls::selection_box::SelectionBox;
 use crate::ui::controls::spin_button::SpinButton;
 use crate::ui::labeled_control::LabeledControl;
+use crate::ui::managed_control::ManagedControl;
+use crate::ui::manager::lockscreen_manager::{LockscreenManager, LockscreenManagerEvent};
 use crate::ui::section_box_builder::SectionBoxBuilder;
 use crate::ui::states::color_selector_state::ColorSelectorState;
 use crate::ui::states::input_field_state::InputFieldState;
@@ -25,7 +28,8 @@ pub struct Lockscreen {
     application_provider: ApplicationProvider,
     state: LockScreenPageState,
     lockscreen_scroll_box: GTKBox,
-    lockscreen_box: GTKBox
+    lockscreen_box: GTKBox,
+    import_button: Button,
 }
 
 impl Control for Lockscreen {
@@ -52,6 +56,14 @@ impl UpdatableControl<LockScreenPageState> for Lockscreen {
     }
 }
 
+impl ManagedControl<LockscreenManager> for Lockscreen {
+    fn init_events_by_manager(&self, lockscreen_manager: LockscreenManager) {
+        self.import_button.connect_clicked(move |_| {
+            lockscreen_manager.send_event(LockscreenManagerEvent::ImportHyprlockConfig);
+        });
+    }
+}
+
 impl Lockscreen {
     pub fn new(application_provider: ApplicationProvider) -> Self {
         let lockscreen_box = BoxBuilder::new("lockscreen")
@@ -64,9 +76,16 @@ impl Lockscreen {
         lockscreen_scroll_window.set_vexpand(true);
         lockscreen_scroll_window.set_child(Some(&lockscreen_box));
 
+        let import_button = Button::with_label("Import from hyprlock.conf");
+        import_button.set_widget_name("lockscreen-import-button");
+        import_button.set_halign(Align::End);
+        import_button.set_margin_end(DEFAULT_MARGIN as i32);
+        import_button.set_sensitive(HyprlockConfigReader::config_file_exists());
+
         let lockscreen_scroll_box = BoxBuilder::new("lockscreen-scroll-box")
             .set_full_height(true)
             .build();
+        lockscreen_scroll_box.append(&import_button);
         lockscreen_scroll_box.append(&lockscreen_scroll_window);
 
         let state: LockScreenPageState = Default::default();
@@ -75,7 +94,8 @@ impl Lockscreen {
             application_provider,
             state,
             lockscreen_scroll_box,
-            lockscreen_box
+            lockscreen_box,
+            import_button,
         }
     }
 
@@ -578,7 +598,7 @@ impl Lockscreen {
 
         let state = SpinButtonState {
             label_text: "X-Position".to_string(),
-            min_value: 0.0,
+            min_value: -100.0,
             max_value: 100.0,
             current_value: lockscreen_state.input_x_position as f64,
             increment_value: 1.0,
@@ -592,7 +612,7 @@ impl Lockscreen {
 
         let lockscreen_provider = self.application_provider.get_lockscreen_provider();
         let input_x_position_change = move |spin_button: &GTKSpinButton| {
-            lockscreen_provider.borrow_mut().set_input_x_position(spin_button.value() as u32);
+            lockscreen_provider.borrow_mut().set_input_x_position(spin_button.value() as i32);
         };
         input_x_position.set_value_change(input_x_position_change);
 
@@ -602,7 +622,7 @@ impl Lockscreen {
 
         let state = SpinButtonState {
             label_text: "Y-Position".to_string(),
-            min_value: 0.0,
+            min_value: -100.0,
             max_value: 100.0,
             current_value: lockscreen_state.input_y_position as f64,
             increment_value: 1.0,
@@ -616,7 +636,7 @@ impl Lockscreen {
 
         let lockscreen_provider = self.application_provider.get_lockscreen_provider();
         let input_y_position_change = move |spin_button: &GTKSpinButton| {
-            lockscreen_provider.borrow_mut().set_input_y_position(spin_button.value() as u32);
+            lockscreen_provider.borrow_mut().set_input_y_position(spin_button.value() as i32);
         };
         input_y_position.set_value_change(input_y_position_change);
 
@@ -758,7 +778,7 @@ impl Lockscreen {
 
         let state = SpinButtonState {
             label_text: "X-Position".to_string(),
-            min_value: 0.0,
+            min_value: -100.0,
             max_value: 100.0,
             current_value: lockscreen_state.display_text_x_position as f64,
             increment_value: 1.0,
@@ -772,7 +792,7 @@ impl Lockscreen {
 
         let lockscreen_provider = self.application_provider.get_lockscreen_provider();
         let text_x_position_spin_change = move |spin_button: &GTKSpinButton| {
-            lockscreen_provider.borrow_mut().set_display_text_x_position(spin_button.value() as u32);
+            lockscreen_provider.borrow_mut().set_display_text_x_position(spin_button.value() as i32);
         };
         text_x_position_spin_button.set_value_change(text_x_position_spin_change);
 
@@ -782,7 +802,7 @@ impl Lockscreen {
 
         let state = SpinButtonState {
             label_text: "Y-Position".to_string(),
-            min_value: 0.0,
+            min_value: -100.0,
             max_value: 100.0,
             current_value: lockscreen_state.display_text_y_position as f64,
             increment_value: 1.0,
@@ -796,7 +816,7 @@ impl Lockscreen {
 
         let lockscreen_provider = self.application_provider.get_lockscreen_provider();
         let text_y_position_spin_change = move |spin_button: &GTKSpinButton| {
-            lockscreen_provider.borrow_mut().set_display_text_y_position(spin_button.value() as u32);
+            lockscreen_provider.borrow_mut().set_display_text_y_position(spin_button.value() as i32);
         };
         text_y_position_spin_button.set_value_change(text_y_position_spin_change);
 
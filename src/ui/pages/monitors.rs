@@ -1,6 +1,7 @@
-use gtk::{DropDown, Orientation, ScrolledWindow, Separator, Switch};
-use gtk::glib::Propagation;
-use gtk::prelude::{BoxExt, WidgetExt};
+use gtk::{Align, Button, DropDown, Label, Orientation, ScrolledWindow, Separator, Switch};
+use gtk::glib::{self, Propagation};
+use gtk::prelude::{BoxExt, ButtonExt, WidgetExt};
+use crate::ipc::hyprland_ipc_client::HyprlandIpcClient;
 use crate::models::monitor::monitor_configuration::MonitorOrientation;
 use crate::providers::application_provider::ApplicationProvider;
 use crate::types::{GTKBox, GTKSpinButton};
@@ -11,9 +12,11 @@ use crate::ui::controls::Control;
 use crate::ui::controls::monitor_configurator::MonitorConfigurator;
 use crate::ui::controls::monitor_field::MonitorField;
 use crate::ui::controls::selection_box::SelectionBox;
+use crate::ui::field_highlighter::FieldHighlighter;
 use crate::ui::managed_control::ManagedControl;
 use crate::ui::manager::monitor_configurator_manager::MonitorConfiguratorManager;
 use crate::ui::manager::monitor_field_manager::{MonitorFieldEvent, MonitorFieldManager};
+use crate::ui::manager::monitors_manager::{MonitorsManager, MonitorsManagerEvent};
 use crate::ui::section_box_builder::SectionBoxBuilder;
 use crate::ui::states::monitor_configurator_state::MonitorConfiguratorState;
 use crate::ui::states::monitor_field_state::MonitorFieldState;
@@ -24,8 +27,12 @@ use crate::utils::new_rc_mut;
 pub struct Monitors {
     state: MonitorsState,
     application_provider: ApplicationProvider,
+    field_highlighter: FieldHighlighter,
     monitor_scroll_box: GTKBox,
-    monitor_box: GTKBox,
+    monitor_content_box: GTKBox,
+    layout_profile_label: Label,
+    try_button: Button,
+    rescan_button: Button,
 }
 
 impl Control for Monitors {
@@ -36,6 +43,17 @@ impl Control for Monitors {
 
 impl UpdatableControl<MonitorsState> for Monitors {
     fn update_state(&mut self, state: MonitorsState) {
+        Boxes::clear_box_content(&self.monitor_content_box);
+        self.try_button.set_visible(state.enabled);
+        self.rescan_button.set_visible(state.enabled);
+        self.layout_profile_label.set_visible(state.enabled);
+
+        let layout_profile_text = match &state.layout_profile_name {
+            Some(layout_profile_name) => format!("Layout profile: {}", layout_profile_name),
+            None => "No layout profile for the connected monitors yet, saving creates one.".to_string(),
+        };
+        self.layout_profile_label.set_label(layout_profile_text.as_str());
+
         if state.enabled {
             self.create_monitor_fields(state.clone());
             self.create_monitor_configurator();
@@ -51,12 +69,70 @@ impl UpdatableControl<MonitorsState> for Monitors {
     }
 }
 
+impl ManagedControl<MonitorsManager> for Monitors {
+    fn init_events_by_manager(&self, monitors_manager: MonitorsManager) {
+        let monitors_manager_clone = monitors_manager.clone();
+        self.try_button.connect_clicked(move |_| {
+            monitors_manager_clone.send_event(MonitorsManagerEvent::TryMonitorSettings);
+        });
+
+        let monitors_manager_clone = monitors_manager.clone();
+        self.rescan_button.connect_clicked(move |_| {
+            monitors_manager_clone.send_event(MonitorsManagerEvent::RescanMonitors);
+        });
+
+        // The page is mapped whenever it is opened, the layout profile is applied afterward since it rebuilds the page
+        self.monitor_scroll_box.connect_map(move |_| {
+            let monitors_manager = monitors_manager.clone();
+            glib::idle_add_local_once(move || {
+                monitors_manager.send_event(MonitorsManagerEvent::ApplyLayoutProfile);
+            });
+        });
+    }
+}
+
 impl Monitors {
-    pub fn new(application_provider: ApplicationProvider) -> Self {
+    pub fn new(application_provider: ApplicationProvider, field_highlighter: FieldHighlighter) -> Self {
         let monitor_box = SectionBoxBuilder::new("monitors", DEFAULT_MARGIN)
             .create_header_elements("Available monitors")
             .build().expect("Failed to create monitor settings section box");
 
+        let monitor_content_box = BoxBuilder::new("monitor-content")
+            .set_orientation(Orientation::Vertical)
+            .build();
+
+        let layout_profile_label = Label::new(None);
+        layout_profile_label.set_halign(Align::Start);
+        layout_profile_label.set_xalign(0.0);
+
+        let monitor_actions_box = BoxBuilder::new("monitor-actions")
+            .set_orientation(Orientation::Horizontal)
+            .build();
+
+        let try_button = Button::with_label("Try monitor settings");
+        try_button.set_widget_name("try-monitor-settings");
+        try_button.set_halign(Align::Start);
+        try_button.set_tooltip_text(Some(
+            "Applies the monitor settings to the running Hyprland and restores the previous ones unless they are kept."
+        ));
+
+        // Trying monitor settings needs the IPC socket of a running Hyprland session
+        try_button.set_sensitive(HyprlandIpcClient::from_environment().is_some());
+
+        let rescan_button = Button::with_label("Rescan monitors");
+        rescan_button.set_widget_name("rescan-monitors");
+        rescan_button.set_halign(Align::Start);
+        rescan_button.set_tooltip_text(Some(
+            "Detects the connected monitors again. New monitors are added, the settings of known monitors are kept."
+        ));
+
+        monitor_actions_box.append(&try_button);
+        monitor_actions_box.append(&rescan_button);
+
+        monitor_box.append(&layout_profile_label);
+        monitor_box.append(&monitor_content_box);
+        monitor_box.append(&monitor_actions_box);
+
         let monitor_scroll_window = ScrolledWindow::new();
         monitor_scroll_window.set_widget_name("monitor-scroll-window");
         monitor_scroll_window.set_vexpand(true);
@@ -72,8 +148,12 @@ impl Monitors {
         Self {
             state,
             application_provider,
+            field_highlighter,
             monitor_scroll_box,
-            monitor_box
+            monitor_content_box,
+            layout_profile_label,
+            try_button,
+            rescan_button
         }
     }
 
@@ -89,8 +169,10 @@ impl Monitors {
             let monitor_field_state = MonitorFieldState {
                 monitor_port: port.clone(),
                 monitor_configuration: configuration.clone(),
+                is_missing: state.missing_monitor_ports.contains(&port),
             };
             monitor_field.borrow_mut().update_state(monitor_field_state);
+            monitor_field.borrow().register_fields(&self.field_highlighter, &port);
 
             let monitor_field_manager = MonitorFieldManager::new(monitor_field.clone());
             let monitor_provider = self.application_provider.get_monitor_provider();
@@ -105,24 +187,11 @@ impl Monitors {
 
             let monitor_provider = self.application_provider.get_monitor_provider();
             let port_clone = port.clone();
-            let width_spin_button_change = move |spin_button: &GTKSpinButton| {
-                monitor_provider.borrow_mut().set_monitor_width(port_clone.clone(), spin_button.value() as u32);
-            };
-            monitor_field.borrow_mut().set_width_change(width_spin_button_change);
-
-            let monitor_provider = self.application_provider.get_monitor_provider();
-            let port_clone = port.clone();
-            let height_spin_button_change = move |spin_button: &GTKSpinButton| {
-                monitor_provider.borrow_mut().set_monitor_height(port_clone.clone(), spin_button.value() as u32);
-            };
-            monitor_field.borrow_mut().set_height_change(height_spin_button_change);
-
-            let monitor_provider = self.application_provider.get_monitor_provider();
-            let port_clone = port.clone();
-            let refresh_rate_spin_button_change = move |spin_button: &GTKSpinButton| {
-                monitor_provider.borrow_mut().set_monitor_refresh_rate(port_clone.clone(), spin_button.value() as u32);
+            let video_mode_selection_box_change = move |dropdown: &DropDown| {
+                let selected_option = SelectionBox::get_selected_option(dropdown);
+                monitor_provider.borrow_mut().set_monitor_video_mode(port_clone.clone(), &selected_option);
             };
-            monitor_field.borrow_mut().set_refresh_rate_change(refresh_rate_spin_button_change);
+            monitor_field.borrow_mut().set_video_mode_change(video_mode_selection_box_change);
 
             let monitor_provider = self.application_provider.get_monitor_provider();
             let port_clone = port.clone();
@@ -144,7 +213,7 @@ impl Monitors {
             monitor_fields_box.append(&separator);
         }
 
-        self.monitor_box.append(&monitor_fields_box);
+        self.monitor_content_box.append(&monitor_fields_box);
     }
 
     fn create_monitor_configurator(&mut self) {
@@ -166,14 +235,15 @@ impl Monitors {
         );
         monitor_configurator.borrow_mut().init_events_by_manager(monitor_configurator_manager);
         
-        self.monitor_box.append(monitor_configurator.borrow().get_widget());
+        self.monitor_content_box.append(monitor_configurator.borrow().get_widget());
     }
 
     fn create_monitors_warning(&mut self) {
         let monitors_warning_box = Boxes::create_warning_box(
-            "⚠️ wlr-randr dependency module not found. This is required to configure the monitors."
+            "⚠️ Neither hyprctl inside of a Hyprland session nor wlr-randr was found. One of them is required to configure \
+            the monitors."
         );
 
-        self.monitor_box.append(&monitors_warning_box);
+        self.monitor_content_box.append(&monitors_warning_box);
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use gtk::{Entry, Orientation, ScrolledWindow};
+use gtk::{DropDown, Entry, Orientation, ScrolledWindow};
 use gtk::prelude::{BoxExt, EditableExt, WidgetExt};
 use crate::providers::application_provider::ApplicationProvider;
 use crate::types::GTKBox;
@@ -6,13 +6,17 @@ use crate::ui::box_builder::BoxBuilder;
 use crate::ui::boxes::{Boxes, DEFAULT_MARGIN};
 use crate::ui::controls::Control;
 use crate::ui::controls::input_field::InputField;
+use crate::ui::controls::selection_box::SelectionBox;
+use crate::ui::field_highlighter::FieldHighlighter;
 use crate::ui::section_box_builder::SectionBoxBuilder;
 use crate::ui::states::input_field_state::InputFieldState;
+use crate::ui::states::selection_box_state::SelectionBoxState;
 use crate::ui::states::wallpaper_page_state::WallpaperPageState;
 use crate::ui::updatable_control::UpdatableControl;
 
 pub struct Wallpaper {
     application_provider: ApplicationProvider,
+    field_highlighter: FieldHighlighter,
     state: WallpaperPageState,
     wallpaper_scroll_box: GTKBox,
     wallpaper_sections_box: GTKBox,
@@ -43,7 +47,7 @@ impl UpdatableControl<WallpaperPageState> for Wallpaper {
 }
 
 impl Wallpaper {
-    pub fn new(application_provider: ApplicationProvider) -> Self {
+    pub fn new(application_provider: ApplicationProvider, field_highlighter: FieldHighlighter) -> Self {
         const WALLPAPER_TITLE: &str = "Wallpaper";
         let wallpaper_box = SectionBoxBuilder::new("wallpaper", DEFAULT_MARGIN)
             .create_header_elements(WALLPAPER_TITLE)
@@ -71,6 +75,7 @@ impl Wallpaper {
 
         Self {
             application_provider,
+            field_highlighter,
             state,
             wallpaper_scroll_box,
             wallpaper_sections_box,
@@ -96,7 +101,38 @@ impl Wallpaper {
         wallpaper_path_input_field.update_state(state);
         wallpaper_path_input_field.set_input_callback(wallpaper_path_input_change);
 
+        let mut splash_selection_box = SelectionBox::new();
+        let state = SelectionBoxState {
+            label_text: "Show splash text:".to_string(),
+            selected_option: Some(appearance_provider.borrow().get_wallpaper_splash().to_string()),
+            options: SelectionBoxState::get_false_true_options(),
+        };
+        splash_selection_box.update_state(state);
+
+        let appearance_provider_clone = appearance_provider.clone();
+        splash_selection_box.set_selection_change(move |dropdown: &DropDown| {
+            let bool_value = SelectionBox::get_selected_option_as_bool(dropdown);
+            appearance_provider_clone.borrow_mut().set_wallpaper_splash(bool_value);
+        });
+
+        let mut ipc_selection_box = SelectionBox::new();
+        let state = SelectionBoxState {
+            label_text: "Enable IPC:".to_string(),
+            selected_option: Some((!appearance_provider.borrow().get_disable_wallpaper_ipc()).to_string()),
+            options: SelectionBoxState::get_false_true_options(),
+        };
+        ipc_selection_box.update_state(state);
+
+        let appearance_provider_clone = appearance_provider.clone();
+        ipc_selection_box.set_selection_change(move |dropdown: &DropDown| {
+            let bool_value = SelectionBox::get_selected_option_as_bool(dropdown);
+            appearance_provider_clone.borrow_mut().set_disable_wallpaper_ipc(!bool_value);
+        });
+
+        self.field_highlighter.register_field("appearance_settings.wallpaper_path", wallpaper_path_input_field.get_widget());
         self.wallpaper_sections_box.append(wallpaper_path_input_field.get_widget());
+        self.wallpaper_sections_box.append(splash_selection_box.get_widget());
+        self.wallpaper_sections_box.append(ipc_selection_box.get_widget());
     }
 
     fn create_wallpaper_warning(&self) {
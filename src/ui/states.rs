@@ -15,4 +15,9 @@ pub mod input_field_state;
 pub mod selection_box_state;
 pub mod monitor_state;
 pub mod monitor_configurator_state;
-pub mod input_state;
\ No newline at end of file
+pub mod input_state;
+pub mod backups_page_state;
+pub mod save_preview_dialog_state;
+pub mod monitor_try_dialog_state;
+pub mod stale_banner_state;
+pub mod profile_selector_state;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use crate::persistence::config_backup::{ConfigBackup, ConfigBackupEntry};
+use crate::providers::application_provider::ApplicationProvider;
+
+#[derive(Clone, Default)]
+pub struct BackupsPageState {
+    pub retention_count: usize,
+    pub split_config_files: bool,
+    pub backup_entries: Vec<ConfigBackupEntry>,
+}
+
+impl From<&ApplicationProvider> for BackupsPageState {
+    fn from(value: &ApplicationProvider) -> Self {
+        let backup_provider = value.get_backup_provider();
+        let retention_count = backup_provider.borrow().get_retention_count();
+        let config_file_provider = value.get_config_file_provider();
+        let split_config_files = config_file_provider.borrow().get_split_config_files();
+
+        Self {
+            retention_count,
+            split_config_files,
+            backup_entries: ConfigBackup::get_backup_entries(),
+        }
+    }
+}
\ No newline at end of file
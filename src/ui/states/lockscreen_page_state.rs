@@ -26,16 +26,16 @@ pub struct LockScreenPageState {
     pub input_font_color: RGBAColor,
     pub input_placeholder_text: Option<String>,
     pub hide_input: bool,
-    pub input_x_position: u32,
-    pub input_y_position: u32,
+    pub input_x_position: i32,
+    pub input_y_position: i32,
     pub input_vertical_alignment: Option<String>,
     pub input_horizontal_alignment: Option<String>,
     pub display_text: Option<String>,
     pub display_text_color: RGBAColor,
     pub display_text_font_size: u32,
     pub display_text_font: Option<String>,
-    pub display_text_x_position: u32,
-    pub display_text_y_position: u32,
+    pub display_text_x_position: i32,
+    pub display_text_y_position: i32,
     pub display_text_vertical_alignment: Option<String>,
     pub display_text_horizontal_alignment: Option<String>,
 }
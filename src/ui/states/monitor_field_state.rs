@@ -4,4 +4,5 @@ use crate::models::monitor::monitor_configuration::MonitorConfiguration;
 pub struct MonitorFieldState {
     pub monitor_port: String,
     pub monitor_configuration: MonitorConfiguration,
+    pub is_missing: bool,
 }
\ No newline at end of file
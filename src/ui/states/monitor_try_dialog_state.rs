@@ -0,0 +1,4 @@
+#[derive(Clone, Default)]
+pub struct MonitorTryDialogState {
+    pub remaining_seconds: u32,
+}
\ No newline at end of file
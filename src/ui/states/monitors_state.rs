@@ -1,26 +1,29 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 use crate::models::monitor::monitor_configuration::MonitorConfiguration;
 use crate::providers::application_provider::ApplicationProvider;
+use crate::providers::monitor_provider::MonitorProvider;
 
 #[derive(Clone, Default)]
 pub struct MonitorsState {
     pub enabled: bool,
-    pub monitor_configurations: HashMap<String, MonitorConfiguration>,
+    pub monitor_configurations: BTreeMap<String, MonitorConfiguration>,
+    pub missing_monitor_ports: BTreeSet<String>,
+    pub layout_profile_name: Option<String>,
 }
 
 impl From<&ApplicationProvider> for MonitorsState {
     fn from(value: &ApplicationProvider) -> Self {
-        let monitor_configurations = value.get_monitor_provider()
-            .borrow()
-            .get_monitor_configurations();
+        let monitor_provider = value.get_monitor_provider();
+        let monitor_configurations = monitor_provider.borrow().get_monitor_configurations();
+        let missing_monitor_ports = monitor_provider.borrow().get_missing_monitor_ports();
+        let layout_profile_name = monitor_provider.borrow().get_matching_layout_profile_name();
 
-        let enabled = value
-            .get_program_provider()
-            .borrow()
-            .has_dependency_module("wlr-randr".to_string());
+        let enabled = MonitorProvider::has_monitor_backend();
 
         Self {
             monitor_configurations,
+            missing_monitor_ports,
+            layout_profile_name,
             enabled
         }
     }
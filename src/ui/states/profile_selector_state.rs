@@ -0,0 +1,16 @@
+use crate::persistence::settings_profiles::SettingsProfiles;
+
+#[derive(Clone, Default)]
+pub struct ProfileSelectorState {
+    pub profile_names: Vec<String>,
+    pub active_profile: Option<String>,
+}
+
+impl ProfileSelectorState {
+    pub fn from_profiles() -> Self {
+        Self {
+            profile_names: SettingsProfiles::get_profile_names(),
+            active_profile: SettingsProfiles::get_active_profile(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use crate::persistence::config_diff::{ConfigDiff, DiffLine};
+use crate::persistence::settings_writer::RenderedConfig;
+
+#[derive(Clone, Default)]
+pub struct SavePreviewDialogState {
+    pub changed_configs: Vec<(String, Vec<DiffLine>)>,
+    pub warnings: Vec<String>,
+}
+
+impl From<&Vec<RenderedConfig>> for SavePreviewDialogState {
+    fn from(value: &Vec<RenderedConfig>) -> Self {
+        let changed_configs = value.iter()
+            .map(|rendered_config| {
+                let current_content = rendered_config.read_current_content();
+                let diff_lines = ConfigDiff::create_line_diff(&current_content, &rendered_config.content);
+                (rendered_config.get_config_name(), diff_lines)
+            })
+            .filter(|(_, diff_lines)| ConfigDiff::has_changes(diff_lines))
+            .collect();
+
+        Self {
+            changed_configs,
+            warnings: Vec::new(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+#[derive(Clone, Default)]
+pub struct StaleBannerState {
+    pub changed_configs: Vec<String>,
+}
\ No newline at end of file